@@ -0,0 +1,151 @@
+use getset::Getters;
+
+use crate::{CleanLyrics, Lyrics};
+
+/// An original line and its translation, as paired by [AlignedLyrics::new].
+///
+/// A line found in only one version has `None` on the other side. Blank lines between stanzas are pairs of empty strings.
+#[derive(Getters)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct LinePair {
+    /// The line of the original lyrics.
+    #[getset(get = "pub")]
+    original : Option<String>,
+
+    /// The translated line.
+    #[getset(get = "pub")]
+    translation : Option<String>,
+}
+
+impl LinePair {
+    /// Whether the pair is a blank line between stanzas.
+    pub fn is_blank(&self) -> bool {
+        self.original.as_deref() == Some("") && self.translation.as_deref() == Some("")
+    }
+}
+
+/// The lines of lyrics paired with the lines of their translation.
+#[derive(Getters)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct AlignedLyrics {
+    /// The pairs, in the order of the lyrics.
+    #[getset(get = "pub")]
+    pairs : Vec<LinePair>,
+}
+
+/// The score of pairing two lines. Blank lines only pair with blank lines, and lines of similar length pair best.
+fn score(original : &str,translation : &str) -> Option<f64> {
+    match (original.is_empty(),translation.is_empty()) {
+        (true,true) => Some(3.0),
+        (false,false) if original.to_lowercase() == translation.to_lowercase() => Some(2.0),
+        (false,false) => {
+            let ratio = original.chars().count() as f64 / translation.chars().count() as f64;
+            Some(1.0 - ratio.ln().abs().min(2.0) / 2.0)
+        },
+        _ => None,
+    }
+}
+
+/// The score of leaving a line without a partner.
+const GAP : f64 = -1.0;
+
+impl AlignedLyrics {
+    /// Pairs the lines of the original lyrics with those of the translation.
+    ///
+    /// Both are cleaned with [CleanLyrics::new] first. Translations usually follow the original line by line, so
+    /// the lines are paired in order, a line being left without a partner only where that lines the rest up
+    /// better: blank lines between stanzas are kept together and lines of similar length are preferred.
+    pub fn new(original : &str,translation : &str) -> Self {
+        let (original,translation) = (CleanLyrics::new(original),CleanLyrics::new(translation));
+        let (a,b) = (original.lines(),translation.lines());
+
+        // best[i][j] is the best score for aligning the first i original and j translated lines.
+        let mut best = vec![vec![0.0f64; b.len() + 1]; a.len() + 1];
+        for i in 0..=a.len() {
+            for j in 0..=b.len() {
+                if i == 0 && j == 0 {
+                    continue;
+                }
+                let mut score_here = f64::NEG_INFINITY;
+                if i > 0 {
+                    score_here = score_here.max(best[i - 1][j] + GAP);
+                }
+                if j > 0 {
+                    score_here = score_here.max(best[i][j - 1] + GAP);
+                }
+                if let Some(score) = (i > 0 && j > 0).then(|| score(&a[i - 1],&b[j - 1])).flatten() {
+                    score_here = score_here.max(best[i - 1][j - 1] + score);
+                }
+                best[i][j] = score_here;
+            }
+        }
+
+        let mut pairs = Vec::with_capacity(a.len().max(b.len()));
+        let (mut i,mut j) = (a.len(),b.len());
+        while i > 0 || j > 0 {
+            let paired = (i > 0 && j > 0).then(|| score(&a[i - 1],&b[j - 1])).flatten();
+            let (original,translation) = match paired {
+                Some(score) if best[i][j] == best[i - 1][j - 1] + score => { i -= 1; j -= 1; (Some(&a[i]),Some(&b[j])) },
+                _ if i > 0 && best[i][j] == best[i - 1][j] + GAP => { i -= 1; (Some(&a[i]),None) },
+                _ => { j -= 1; (None,Some(&b[j])) },
+            };
+            // A blank line in only one version adds nothing.
+            if !matches!((original,translation),(Some(line),None) | (None,Some(line)) if line.is_empty()) {
+                pairs.push(LinePair { original : original.cloned(), translation : translation.cloned() });
+            }
+        }
+        pairs.reverse();
+        AlignedLyrics { pairs }
+    }
+
+    /// The share of original lines that have a translation, from 0 to 1, as the `min_completed` parameter of
+    /// [MusixAbgleich::track_lyrics_translations](crate::MusixAbgleich::track_lyrics_translations) uses. Lyrics without lines are complete.
+    pub fn completeness(&self) -> f32 {
+        let lines = self.pairs.iter().filter(|pair| pair.original.as_deref().is_some_and(|line| !line.is_empty()));
+        translated_share(lines.map(|pair| pair.translation.is_some()))
+    }
+}
+
+/// The share of `true`s among whether each original line is translated, `1.0` if there are none.
+pub(crate) fn translated_share(translated : impl Iterator<Item = bool>) -> f32 {
+    let (total,translated) = translated.fold((0,0),|(total,translated),is_translated| (total + 1,translated + is_translated as usize));
+    match total {
+        0 => 1.0,
+        total => translated as f32 / total as f32,
+    }
+}
+
+impl Lyrics {
+    /// Pairs the lines of these lyrics with the lines of their `translation`, see [AlignedLyrics::new].
+    pub fn align_with(&self,translation : &Lyrics) -> AlignedLyrics {
+        AlignedLyrics::new(self.lyrics(),translation.lyrics())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(aligned : &AlignedLyrics) -> Vec<(Option<&str>,Option<&str>)> {
+        aligned.pairs().iter().map(|pair| (pair.original().as_deref(),pair.translation().as_deref())).collect()
+    }
+
+    #[test]
+    fn aligns_lines_and_stanzas() {
+        let original = "Work it harder\nMake it better\n\nDo it faster\nMakes us stronger\nOh oh oh\n\n******* This Lyrics is NOT for Commercial use *******";
+        let translation = "Travaille plus dur\nRends-le meilleur\n\n\nFais-le vite\nOh oh oh";
+        let aligned = AlignedLyrics::new(original, translation);
+
+        assert_eq!(pairs(&aligned), [
+            (Some("Work it harder"), Some("Travaille plus dur")),
+            (Some("Make it better"), Some("Rends-le meilleur")),
+            (Some(""), Some("")),
+            (Some("Do it faster"), Some("Fais-le vite")),
+            (Some("Makes us stronger"), None),
+            (Some("Oh oh oh"), Some("Oh oh oh")),
+        ]);
+        assert!(aligned.pairs()[2].is_blank());
+        assert_eq!(aligned.completeness(), 0.8);
+        assert_eq!(AlignedLyrics::new("", "").completeness(), 1.0);
+    }
+}
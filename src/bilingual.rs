@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use api_request_utils::serde_json::{self, json};
+use getset::Getters;
+
+use crate::{align::translated_share, ExportOptions, SyncedLine, SyncedLyrics, VttCueSettings};
+
+/// How far apart an original line and a translated line may start and still be paired.
+const TOLERANCE : Duration = Duration::from_secs(1);
+
+/// A line of synced lyrics together with its translation.
+#[derive(Getters)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct BilingualCue {
+    /// The time from the start of the song.
+    #[getset(get = "pub")]
+    time : Duration,
+
+    /// The time the original line stops being shown at, if known.
+    #[getset(get = "pub")]
+    end : Option<Duration>,
+
+    /// The original line, empty for instrumental breaks and for translated lines that match no original line.
+    #[getset(get = "pub")]
+    original : String,
+
+    /// The translated line, `None` if no translated line starts at the same time.
+    #[getset(get = "pub")]
+    translation : Option<String>,
+}
+
+/// Synced lyrics where every cue holds the original line and its translation, for showing both at once.
+#[derive(Getters)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct BilingualLyrics {
+    /// The metadata tags of the original lyrics.
+    #[getset(get = "pub")]
+    tags : Vec<(String,String)>,
+
+    /// The cues, ordered by time.
+    #[getset(get = "pub")]
+    cues : Vec<BilingualCue>,
+}
+
+impl BilingualLyrics {
+    /// Pairs every original line with the translated line starting closest to it, within a second.
+    ///
+    /// Translated subtitles normally share the times of the original, so this pairs them line by line. An original
+    /// line without a match keeps no translation, and a translated line without a match becomes a cue of its own,
+    /// so nothing is lost when the two do not line up.
+    pub fn new(original : &SyncedLyrics,translation : &SyncedLyrics) -> Self {
+        let mut unused : Vec<&SyncedLine> = translation.lines().iter().filter(|line| !line.text().trim().is_empty()).collect();
+
+        let mut cues : Vec<BilingualCue> = original.lines().iter()
+            .map(|line| {
+                let closest = match line.text().trim().is_empty() {
+                    true => None,
+                    false => unused.iter().enumerate()
+                        .map(|(index,other)| (index,line.time().abs_diff(*other.time())))
+                        .filter(|(_,distance)| *distance <= TOLERANCE)
+                        .min_by_key(|(_,distance)| *distance)
+                        .map(|(index,_)| unused.remove(index)),
+                };
+                BilingualCue { time : *line.time(), end : *line.end(), original : line.text().clone(), translation : closest.map(|line| line.text().clone()) }
+            })
+            .collect();
+
+        for line in unused {
+            let index = cues.partition_point(|cue| cue.time <= *line.time());
+            cues.insert(index,BilingualCue { time : *line.time(), end : *line.end(), original : String::new(), translation : Some(line.text().clone()) });
+        }
+        BilingualLyrics { tags : original.tags().clone(), cues }
+    }
+
+    /// The share of original lines that have a translation, from 0 to 1, as [AlignedLyrics::completeness](crate::AlignedLyrics::completeness) measures it.
+    pub fn completeness(&self) -> f32 {
+        let lines = self.cues.iter().filter(|cue| !cue.original.trim().is_empty());
+        translated_share(lines.map(|cue| cue.translation.is_some()))
+    }
+
+    /// The cues as synced lyrics, each line showing the original above the translation.
+    pub fn to_synced_lyrics(&self) -> SyncedLyrics {
+        let lines = self.cues.iter()
+            .map(|cue| {
+                let text = match &cue.translation {
+                    Some(translation) if !cue.original.is_empty() => format!("{}\n{translation}",cue.original),
+                    Some(translation) => translation.clone(),
+                    None => cue.original.clone(),
+                };
+                match cue.end {
+                    Some(end) => SyncedLine::new(cue.time,text).with_end(end),
+                    None => SyncedLine::new(cue.time,text),
+                }
+            })
+            .collect();
+        SyncedLyrics { tags : self.tags.clone(), lines }
+    }
+
+    /// Writes SubRip subtitles with the original and the translation on two rows of every cue.
+    pub fn to_srt(&self,options : &ExportOptions) -> String {
+        self.to_synced_lyrics().to_srt(options)
+    }
+
+    /// Writes WebVTT subtitles with the original and the translation on two rows of every cue.
+    pub fn to_vtt(&self,options : &ExportOptions,cue_settings : &VttCueSettings) -> String {
+        self.to_synced_lyrics().to_vtt(options,cue_settings)
+    }
+
+    /// Writes the cues as a JSON array of `{"start","end","original","translation"}` objects, with times in seconds
+    /// and end times resolved as described on [ExportOptions]. Instrumental breaks are left out.
+    pub fn to_json(&self,options : &ExportOptions) -> String {
+        let synced = self.to_synced_lyrics();
+        let cues : Vec<_> = self.cues.iter().enumerate()
+            .filter(|(_,cue)| !cue.original.trim().is_empty() || cue.translation.is_some())
+            .map(|(index,cue)| json!({
+                "start" : cue.time.as_secs_f64(),
+                "end" : synced.line_end(index,options).as_secs_f64(),
+                "original" : (!cue.original.is_empty()).then_some(&cue.original),
+                "translation" : cue.translation,
+            }))
+            .collect();
+        serde_json::to_string(&cues).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_lines_and_exports() {
+        let original = SyncedLyrics::from_lrc("[length:00:10]\n[00:01.00]Work it\n[00:02.00]Make it\n[00:03.00]\n[00:04.00]Do it");
+        let translation = SyncedLyrics::from_lrc("[00:01.00]Travaille\n[00:04.30]Fais-le\n[00:08.00]Plus vite");
+        let bilingual = BilingualLyrics::new(&original, &translation);
+
+        let pairs : Vec<_> = bilingual.cues().iter().map(|cue| (cue.time().as_millis(), cue.original().as_str(), cue.translation().as_deref())).collect();
+        assert_eq!(pairs, [(1000, "Work it", Some("Travaille")), (2000, "Make it", None), (3000, "", None), (4000, "Do it", Some("Fais-le")), (8000, "", Some("Plus vite"))]);
+        assert_eq!(bilingual.completeness(), 2.0 / 3.0);
+
+        assert!(bilingual.to_srt(&ExportOptions::new()).starts_with("1\n00:00:01,000 --> 00:00:02,000\nWork it\nTravaille\n\n2\n00:00:02,000 --> 00:00:03,000\nMake it\n\n"));
+        assert_eq!(bilingual.to_json(&ExportOptions::new()), r#"[{"end":2.0,"original":"Work it","start":1.0,"translation":"Travaille"},{"end":3.0,"original":"Make it","start":2.0,"translation":null},{"end":8.0,"original":"Do it","start":4.0,"translation":"Fais-le"},{"end":10.0,"original":null,"start":8.0,"translation":"Plus vite"}]"#);
+    }
+}
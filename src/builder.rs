@@ -1,11 +1,151 @@
 #![allow(unused_results)]
 
-use std::collections::HashMap;
-use api_request_utils::serde_json::Value;
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Deref,
+};
+use api_request_utils::{
+    serde_json::{Value, Error as JsonError, from_value},
+    serde::de::DeserializeOwned,
+};
 
-use crate::SortBy;
+use crate::{SortBy, Language, PartialDate, Track, Page, ArtistId, ValidationError};
+
+/// A query for a single endpoint of the API.
+///
+/// Each query builder is tied to the endpoint it is sent to and to the type its response is mapped into,
+/// so that [MusixAbgleich::request](crate::MusixAbgleich::request) can send any of them.
+pub trait Query : Sized + Send + Sync {
+    /// The endpoint the query is sent to, relative to the base url.
+    const ENDPOINT : &'static str;
+
+    /// The type the response is mapped into.
+    type Response : DeserializeOwned;
+
+    /// Checks every parameter against its documented range.
+    fn validate(&self) -> Result<(),ValidationError>;
+
+    /// The parameters sent along with the request.
+    fn parameters(&self) -> &HashMap<&'static str,Value>;
+
+    /// Maps the `message` object of a successful response into [Query::Response].
+    ///
+    /// Fails if the body does not have the shape of [Query::Response].
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError>;
+
+    /// Validates the query, making it ready to be sent.
+    fn build(self) -> Result<Validated<Self>,ValidationError> {
+        self.validate()?;
+        Ok(Validated(self))
+    }
+}
+
+/// A [Query] for an endpoint whose results are split into pages of `T`.
+pub trait PagedQuery<T> : Query<Response = Page<T>> + Clone {
+    /// Points the query at the given page.
+    fn at_page(self,page : u32,page_size : u8) -> Self;
+}
+
+/// A query whose parameters have been checked against their documented ranges.
+///
+/// Only obtainable through [Query::build].
+#[derive(Clone, Debug)]
+pub struct Validated<Q>(pub(crate) Q);
+
+impl<Q> Validated<Q> {
+    /// Consumes the wrapper, returning the validated query.
+    pub fn into_inner(self) -> Q {
+        self.0
+    }
+}
+
+impl<Q> Deref for Validated<Q> {
+    type Target = Q;
+
+    fn deref(&self) -> &Q {
+        &self.0
+    }
+}
+
+impl<Q : Display> Display for Validated<Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.0.fmt(f)
+    }
+}
+
+/// Inserts `value` under `key`, or removes the key when `value` is `None` so it is not sent at all.
+pub(crate) fn set(parameters : &mut HashMap<&'static str,Value>,key : &'static str,value : Option<impl Into<Value>>) {
+    match value {
+        Some(value) => parameters.insert(key, value.into()),
+        None => parameters.remove(key),
+    };
+}
+
+/// Checks that the numeric value stored under `key`, if any, lies within `min..=max`.
+pub(crate) fn check_range(parameters : &HashMap<&'static str,Value>,key : &'static str,min : f64,max : f64) -> Result<(),ValidationError> {
+    let value = parameters.get(key).and_then(|value| match value {
+        Value::String(value) => value.parse().ok(),
+        value => value.as_f64(),
+    });
+
+    match value {
+        Some(value) if !(min..=max).contains(&value) => Err(ValidationError::OutOfRange { parameter : key, value, min, max }),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the `page` and `page_size` parameters of a paged query.
+pub(crate) fn check_page(parameters : &HashMap<&'static str,Value>) -> Result<(),ValidationError> {
+    check_range(parameters, "page", 1.0, f64::from(u32::MAX))?;
+    check_range(parameters, "page_size", 1.0, 100.0)
+}
+
+/// Checks that at least one of `keys` is set.
+pub(crate) fn require_one(parameters : &HashMap<&'static str,Value>,keys : &'static [&'static str]) -> Result<(),ValidationError> {
+    match keys.iter().any(|key| parameters.contains_key(key)) {
+        true => Ok(()),
+        false => Err(ValidationError::MissingParameter { parameters : keys }),
+    }
+}
+
+/// Sets the `page` and `page_size` parameters of a paged query.
+pub(crate) fn set_page(parameters : &mut HashMap<&'static str,Value>,page : u32,page_size : u8) {
+    parameters.insert("page", Value::from(page));
+    parameters.insert("page_size", Value::from(page_size));
+}
+
+/// Deserializes the object stored under `key` in the body of `message`, or the whole body when `key` is `None`.
+pub(crate) fn body_item<O : DeserializeOwned>(message : &Value,key : Option<&str>) -> Result<O,JsonError> {
+    let body = &message["body"];
+    let value = match key {
+        Some(key) => &body[key],
+        None => body,
+    };
+    from_value::<O>(value.clone())
+}
+
+/// Deserializes a list such as `{ "track_list" : [ { "track" : {..} } ] }` from the body of `message`.
+pub(crate) fn body_list<O : DeserializeOwned>(message : &Value,list_key : &str,item_key : &str) -> Result<Vec<O>,JsonError> {
+    message["body"][list_key].as_array()
+        .into_iter()
+        .flatten()
+        .map(|item| from_value::<O>(item[item_key].clone()))
+        .collect()
+}
+
+/// Deserializes a paged list from `message`, reading the total from `header.available`.
+///
+/// `page` and `page_size` are taken from the request parameters, falling back to the API defaults of 1 and 10.
+pub(crate) fn body_page<O : DeserializeOwned>(message : &Value,parameters : &HashMap<&'static str,Value>,list_key : &str,item_key : &str) -> Result<Page<O>,JsonError> {
+    let parameter = |key : &str| parameters.get(key).and_then(Value::as_u64).and_then(|value| u32::try_from(value).ok());
+    let available = message["header"]["available"].as_u64().and_then(|available| u32::try_from(available).ok());
+
+    Ok(Page::new(body_list(message,list_key,item_key)?,parameter("page").unwrap_or(1),parameter("page_size").unwrap_or(10),available))
+}
 
 /// A struct representing a query for searching track
+#[derive(Default, Clone, Debug)]
 pub struct TrackSearchQuery(pub(crate) HashMap<&'static str,Value>);
 
 impl TrackSearchQuery {
@@ -17,119 +157,233 @@ impl TrackSearchQuery {
     /// Set the song title to search for.
     pub fn song_title(mut self, q_track: Option<&str>) -> Self {
         static KEY : &str = "q_track";
-        self.0.insert(KEY, q_track.into());
+        set(&mut self.0, KEY, q_track);
         self
     }
 
     /// Set the song artist to search for.
     pub fn song_artist(mut self, q_artist: Option<&str>) -> Self {
         static KEY : &str = "q_artist";
-        self.0.insert(KEY, q_artist.into());
+        set(&mut self.0, KEY, q_artist);
         self
     }
 
     /// Set any word to search for in the lyrics.
     pub fn lyrics_contain_word(mut self, q_lyrics: Option<&str>) -> Self {
         static KEY : &str = "q_lyrics";
-        self.0.insert(KEY, q_lyrics.into());
+        set(&mut self.0, KEY, q_lyrics);
         self
     }
 
     /// Set any word to search for in the song title or artist name.
     pub fn song_title_or_artist_contains_word(mut self, q_track_artist: Option<&str>) -> Self {
         static KEY : &str = "q_track_artist";
-        self.0.insert(KEY, q_track_artist.into());
+        set(&mut self.0, KEY, q_track_artist);
         self
     }
 
     /// Search among writers for a specific word.
     pub fn search_writers(mut self, q_writer: Option<&str>) -> Self {
         static KEY : &str = "q_writer";
-        self.0.insert(KEY, q_writer.into());
+        set(&mut self.0, KEY, q_writer);
         self
     }
 
     /// Set any word to search for in the song title, artist name, or lyrics.
     pub fn search_all_contains_word(mut self, q: Option<&str>) -> Self {
         static KEY : &str = "q";
-        self.0.insert(KEY,q.into());
+        set(&mut self.0, KEY, q);
         self
     }
 
     /// Filter by the artist ID.
-    pub fn artist_id(mut self, f_artist_id: Option<u32>) -> Self {
+    pub fn artist_id(mut self, f_artist_id: Option<ArtistId>) -> Self {
         static KEY : &str = "f_artist_id";
-        self.0.insert(KEY, f_artist_id.into());
+        set(&mut self.0, KEY, f_artist_id);
         self
     }
 
     /// Filter by the music category ID.
     pub fn music_genre_id(mut self, f_music_genre_id: Option<u32>) -> Self {
         static KEY : &str = "f_music_genre_id";
-        self.0.insert(KEY, f_music_genre_id.into());
+        set(&mut self.0, KEY, f_music_genre_id);
         self
     }
 
-    /// Filter by the lyrics language (en, it, ...).
-    pub fn lyrics_language(mut self, f_lyrics_language: Option<&str>) -> Self {
+    /// Filter by the lyrics language.
+    pub fn lyrics_language(mut self, f_lyrics_language: Option<&Language>) -> Self {
         static KEY : &str = "f_lyrics_language";
-        self.0.insert(KEY, f_lyrics_language.into());
+        set(&mut self.0, KEY, f_lyrics_language);
         self
     }
 
     /// Filter only contents with lyrics when set to true.
     pub fn has_lyrics(mut self, f_has_lyrics: Option<bool>) -> Self {
         static KEY : &str = "f_has_lyrics";
-        self.0.insert(KEY, f_has_lyrics.into());
+        set(&mut self.0, KEY, f_has_lyrics);
         self
     }
 
-    /// Filter the tracks with a release date newer than the specified value (format: YYYYMMDD).
-    pub fn track_release_group_first_release_date_min(mut self, f_track_release_group_first_release_date_min: Option<&str>) -> Self {
+    /// Filter the tracks with a release date newer than the specified value.
+    ///
+    /// A partial date starts at its first day, so `2001` is sent as `20010101`.
+    pub fn track_release_group_first_release_date_min(mut self, f_track_release_group_first_release_date_min: Option<PartialDate>) -> Self {
         static KEY : &str = "f_track_release_group_first_release_date_min";
-        self.0.insert(KEY, f_track_release_group_first_release_date_min.into());
+        set(&mut self.0, KEY, f_track_release_group_first_release_date_min.map(|date| PartialDate::format_compact(date.first_day())));
         self
     }
 
-    /// Filter the tracks with a release date older than the specified value (format: YYYYMMDD).
-    pub fn track_release_group_first_release_date_max(mut self, f_track_release_group_first_release_date_max: Option<&str>) -> Self {
+    /// Filter the tracks with a release date older than the specified value.
+    ///
+    /// A partial date ends at its last day, so `2001` is sent as `20011231`.
+    pub fn track_release_group_first_release_date_max(mut self, f_track_release_group_first_release_date_max: Option<PartialDate>) -> Self {
         static KEY : &str = "f_track_release_group_first_release_date_max";
-        self.0.insert(KEY, f_track_release_group_first_release_date_max.into());
+        set(&mut self.0, KEY, f_track_release_group_first_release_date_max.map(|date| PartialDate::format_compact(date.last_day())));
         self
     }
 
     /// Sort by our popularity index for artists (asc|desc).
     pub fn artist_rating(mut self, s_artist_rating: Option<SortBy>) -> Self {
         static KEY : &str = "s_artist_rating";
-        self.0.insert(KEY, s_artist_rating.into());
+        set(&mut self.0, KEY, s_artist_rating);
         self
     }
 
     /// Sort by our popularity index for tracks (asc|desc).
     pub fn track_rating(mut self, s_track_rating: Option<SortBy>) -> Self {
-        static KEY : &str = "q_artist";
-        self.0.insert(KEY, s_track_rating.into());
+        static KEY : &str = "s_track_rating";
+        set(&mut self.0, KEY, s_track_rating);
         self
     }
 
     /// Search only a part of the given query string. Allowed range is (0.1 - 0.9).
     pub fn quorum_factor(mut self, quorum_factor: Option<f32>) -> Self {
-        static KEY : &str = "q_artist";
-        self.0.insert(KEY, quorum_factor.into());
+        static KEY : &str = "quorum_factor";
+        // Sent as text so that e.g. 0.1 is not widened to 0.10000000149011612.
+        set(&mut self.0, KEY, quorum_factor.map(|quorum_factor| quorum_factor.to_string()));
         self
     }
 
     /// Define the page number for paginated results.
-    pub fn page(mut self, page: Option<u8>) -> Self {
-        static KEY : &str = "q_artist";
-        self.0.insert(KEY, page.into());
+    pub fn page(mut self, page: Option<u32>) -> Self {
+        static KEY : &str = "page";
+        set(&mut self.0, KEY, page);
         self
     }
 
     /// Define the page size for paginated results. Range is 1 to 100.
     pub fn page_size(mut self, page_size: Option<u32>) -> Self {
-        static KEY : &str = "q_artist";
-        self.0.insert(KEY, page_size.into());
+        static KEY : &str = "page_size";
+        set(&mut self.0, KEY, page_size);
         self
     }
-}
\ No newline at end of file
+}
+
+impl Query for TrackSearchQuery {
+    const ENDPOINT : &'static str = "track.search";
+    type Response = Page<Track>;
+
+    /// `quorum_factor` must lie within 0.1 to 0.9, `page` must be at least 1 and `page_size` must lie within 1 to 100.
+    fn validate(&self) -> Result<(),ValidationError> {
+        check_range(&self.0, "quorum_factor", 0.1, 0.9)?;
+        check_page(&self.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_page(message, &self.0, "track_list", "track")
+    }
+}
+
+impl PagedQuery<Track> for TrackSearchQuery {
+    fn at_page(mut self,page : u32,page_size : u8) -> Self {
+        set_page(&mut self.0, page, page_size);
+        self
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{out_of_range, query_string};
+
+    fn assert_query(query : TrackSearchQuery,expected : &str) {
+        assert_eq!(query_string(query), expected);
+    }
+
+    #[test]
+    fn each_setter_writes_its_own_key() {
+        assert_query(TrackSearchQuery::new().song_title(Some("Harder Better")), "q_track=Harder+Better");
+        assert_query(TrackSearchQuery::new().song_artist(Some("Daft Punk")), "q_artist=Daft+Punk");
+        assert_query(TrackSearchQuery::new().lyrics_contain_word(Some("work")), "q_lyrics=work");
+        assert_query(TrackSearchQuery::new().song_title_or_artist_contains_word(Some("punk")), "q_track_artist=punk");
+        assert_query(TrackSearchQuery::new().search_writers(Some("bangalter")), "q_writer=bangalter");
+        assert_query(TrackSearchQuery::new().search_all_contains_word(Some("robot")), "q=robot");
+        assert_query(TrackSearchQuery::new().artist_id(Some(ArtistId(118))), "f_artist_id=118");
+        assert_query(TrackSearchQuery::new().music_genre_id(Some(17)), "f_music_genre_id=17");
+        assert_query(TrackSearchQuery::new().lyrics_language(Some(&Language::English)), "f_lyrics_language=en");
+        assert_query(TrackSearchQuery::new().has_lyrics(Some(true)), "f_has_lyrics=true");
+        assert_query(TrackSearchQuery::new().track_release_group_first_release_date_min(PartialDate::from_year(2001)), "f_track_release_group_first_release_date_min=20010101");
+        assert_query(TrackSearchQuery::new().track_release_group_first_release_date_max(PartialDate::from_year(2001)), "f_track_release_group_first_release_date_max=20011231");
+        assert_query(TrackSearchQuery::new().track_release_group_first_release_date_max(PartialDate::from_year_month(2004,2)), "f_track_release_group_first_release_date_max=20040229");
+        assert_query(TrackSearchQuery::new().artist_rating(Some(SortBy::Ascending)), "s_artist_rating=asc");
+        assert_query(TrackSearchQuery::new().track_rating(Some(SortBy::Desecending)), "s_track_rating=desc");
+        assert_query(TrackSearchQuery::new().quorum_factor(Some(0.1)), "quorum_factor=0.1");
+        assert_query(TrackSearchQuery::new().page(Some(300)), "page=300");
+        assert_query(TrackSearchQuery::new().page_size(Some(100)), "page_size=100");
+    }
+
+    #[test]
+    fn setters_do_not_overwrite_each_other() {
+        let query = TrackSearchQuery::new()
+            .song_artist(Some("Daft Punk"))
+            .track_rating(Some(SortBy::Desecending))
+            .quorum_factor(Some(0.5))
+            .page(Some(2))
+            .page_size(Some(50));
+
+        assert_query(query, "page=2&page_size=50&q_artist=Daft+Punk&quorum_factor=0.5&s_track_rating=desc");
+    }
+
+    #[test]
+    fn none_removes_the_parameter() {
+        let query = TrackSearchQuery::new()
+            .song_title(Some("Around the World"))
+            .page(Some(3))
+            .song_title(None)
+            .page(None);
+
+        assert_query(query, "");
+    }
+
+    #[test]
+    fn build_rejects_out_of_range_values() {
+        assert_eq!(out_of_range(TrackSearchQuery::new().quorum_factor(Some(0.05))), "quorum_factor");
+        assert_eq!(out_of_range(TrackSearchQuery::new().quorum_factor(Some(0.95))), "quorum_factor");
+        assert_eq!(out_of_range(TrackSearchQuery::new().page(Some(0))), "page");
+        assert_eq!(out_of_range(TrackSearchQuery::new().page_size(Some(0))), "page_size");
+        assert_eq!(out_of_range(TrackSearchQuery::new().page_size(Some(101))), "page_size");
+    }
+
+    #[test]
+    fn build_accepts_range_bounds() {
+        assert!(TrackSearchQuery::new().quorum_factor(Some(0.1)).page_size(Some(1)).page(Some(1)).build().is_ok());
+        assert!(TrackSearchQuery::new().quorum_factor(Some(0.9)).page_size(Some(100)).build().is_ok());
+    }
+
+    #[test]
+    fn malformed_bodies_are_errors() {
+        use api_request_utils::serde_json::json;
+
+        let message = json!({ "header" : { "available" : 3 }, "body" : { "track_list" : [ { "track" : { "track_id" : "not a track" } } ] } });
+        assert!(TrackSearchQuery::new().response(&message).is_err());
+        assert!(body_item::<u32>(&json!({ "body" : { "url" : 1 } }), Some("url")).is_ok());
+        assert!(body_item::<String>(&json!({ "body" : { "url" : 1 } }), Some("url")).is_err());
+        assert!(body_list::<u32>(&json!({ "body" : { "list" : [ { "item" : 1 }, { "item" : "two" } ] } }), "list", "item").is_err());
+
+        let page = TrackSearchQuery::new().page(Some(2)).page_size(Some(5)).response(&json!({ "header" : { "available" : 6 }, "body" : { "track_list" : [] } })).unwrap();
+        assert_eq!((page.page(), page.page_size(), page.available()), (&2, &5, &Some(6)));
+    }
+}
@@ -0,0 +1,116 @@
+use getset::Getters;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::Lyrics;
+
+/// The start of the footer the API puts under the lyrics.
+const DISCLAIMER : &str = "******* This Lyrics is NOT for Commercial use";
+
+/// Whether a line after the footer is blank or the tracking number, such as `(1409617737497)`.
+fn is_after_footer(line : &str) -> bool {
+    let line = line.trim();
+    line.is_empty() || line.strip_prefix('(').and_then(|line| line.strip_suffix(')')).is_some_and(|number| number.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// The lines of a lyrics body with the boilerplate the API adds removed, see [Lyrics::clean_lines].
+#[derive(Getters)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Default)]
+pub struct CleanLyrics {
+    /// The lines, trimmed and NFC-normalised. Verses are separated by a single empty line.
+    #[getset(get = "pub")]
+    lines : Vec<String>,
+
+    /// Whether the body was cut off with `...`, as it is for plans that only get part of the lyrics.
+    #[getset(get = "pub")]
+    is_truncated : bool,
+}
+
+impl CleanLyrics {
+    /// Cleans a lyrics body:
+    ///
+    /// * the `******* This Lyrics is NOT for Commercial use *******` footer and the tracking number after it are removed
+    ///   when they end the body, a censored line such as `******* you` is kept,
+    /// * a final `...` line marking cut off lyrics is removed and reported by [CleanLyrics::is_truncated],
+    /// * line endings and Unicode (NFC) are normalised, runs of whitespace become a single space and lines are trimmed,
+    /// * blank lines at the start and end are removed and runs of blank lines become one.
+    pub fn new(body : &str) -> Self {
+        let body : String = body.replace("\r\n","\n").replace('\r',"\n").nfc().collect();
+        let body : Vec<&str> = body.lines().collect();
+        let end = body.iter()
+            .rposition(|line| line.trim_start().starts_with(DISCLAIMER))
+            .filter(|&footer| body[footer + 1..].iter().all(|line| is_after_footer(line)))
+            .unwrap_or(body.len());
+        let mut lines : Vec<String> = Vec::new();
+
+        for line in &body[..end] {
+            let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+            if !line.is_empty() || lines.last().is_some_and(|last| !last.is_empty()) {
+                lines.push(line);
+            }
+        }
+
+        while lines.last().is_some_and(String::is_empty) {
+            let _ = lines.pop();
+        }
+        let is_truncated = lines.last().is_some_and(|last| last == "..." || last == "…");
+        if is_truncated {
+            let _ = lines.pop();
+            while lines.last().is_some_and(String::is_empty) {
+                let _ = lines.pop();
+            }
+        }
+
+        CleanLyrics { lines, is_truncated }
+    }
+
+    /// The lines joined by `\n`.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Whether no lyrics are left.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+impl Lyrics {
+    /// The lyrics without the commercial use disclaimer and tracking number, with whitespace and Unicode normalised.
+    pub fn clean_lines(&self) -> CleanLyrics {
+        CleanLyrics::new(self.lyrics())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removes_boilerplate() {
+        let body = "\r\n  Work it,\tmake it\r\nDo it\r\n\r\n\r\n\r\nCafe\u{301}\n...\n\n******* This Lyrics is NOT for Commercial use *******\n(1409617737497)";
+        let clean = CleanLyrics::new(body);
+
+        assert_eq!(clean.lines(), &["Work it, make it", "Do it", "", "Café"]);
+        assert!(clean.is_truncated());
+        assert_eq!(clean.text(), "Work it, make it\nDo it\n\nCafé");
+
+        let complete = CleanLyrics::new("Work it\n\n******* This Lyrics is NOT for Commercial use *******\n(1409617737497)");
+        assert_eq!((complete.lines().as_slice(), *complete.is_truncated()), (["Work it".to_owned()].as_slice(), false));
+    }
+
+    #[test]
+    fn keeps_censored_lines() {
+        let body = "Work it
+******* you
+Make it
+
+******* This Lyrics is NOT for Commercial use *******
+(1409617737497)";
+        assert_eq!(CleanLyrics::new(body).lines(), &["Work it", "******* you", "Make it"]);
+
+        let body = "Work it
+******* This Lyrics is NOT for Commercial use *******
+Make it";
+        assert_eq!(CleanLyrics::new(body).lines(), &["Work it", "******* This Lyrics is NOT for Commercial use *******", "Make it"]);
+    }
+}
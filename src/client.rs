@@ -1,4 +1,4 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::borrow::Cow;
 
 use api_request_utils::{
     RequestInfo,
@@ -10,16 +10,15 @@ use api_request_utils::{
         Client,
         RequestBuilder,
     },
-    serde_json::{
-        Value,
-        from_value
-    },
+    serde_json::{Value, json},
     serde::de::DeserializeOwned
 };
 
 use crate::{
     SubtitleFormat, 
     Chart,
+    Country,
+    Language,
     SortBy,
 
     Artist,
@@ -29,9 +28,39 @@ use crate::{
     Snippet,
     Genre,
     Subtitle,
+    MatchedSubtitle,
     Album,
-
-    TrackSearchQuery
+    Page,
+    BilingualLyrics,
+    TranslationSet,
+
+    CommonTrackId,
+    ArtistId,
+    AlbumId,
+    Mbid,
+
+    TrackLocator,
+    ArtistLocator,
+    AlbumLocator,
+
+    Query,
+    Validated,
+    TrackSearchQuery,
+    ChartArtistsQuery,
+    ChartTracksQuery,
+    TrackMatchQuery,
+    LyricsMatchQuery,
+    LyricsQuery,
+    TranslationQuery,
+    MoodQuery,
+    SnippetQuery,
+    SubtitleQuery,
+    SubtitleMatchQuery,
+    SubtitleTranslationQuery,
+    ArtistSearchQuery,
+    AlbumQuery,
+    GenresQuery,
+    TrackingUrlQuery,
 };
 
 /// Represents a client for accessing the MusicMatch API.
@@ -76,7 +105,9 @@ impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send
     /// # Arguments
     ///
     /// * `api_key` - A reference to a string representing the API key used for authentication.
-    /// * `error_resolver` - This is responsible for handling errors that occur during API requests.
+    /// * `error_resolver` - This is responsible for handling errors that occur during API requests. Parameters rejected
+    ///   by [Query::build] are reported as a [RequestError::ErrorPayload] shaped like the API's own bad request answer,
+    ///   `{"message":{"header":{"status_code":400,"hint":..}}}`, without sending anything.
     pub fn new(api_key : impl Into<Cow<'a, str>>,error_resolver : F) -> Self {
         MusixAbgleich {
             client : Client::new(),
@@ -85,238 +116,158 @@ impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send
         }//: Box::new(error_resolver)}
     }
 
-    fn create_map<O : DeserializeOwned>(value_mapper : impl FnOnce(&Value) -> &Value + Send + Sync) -> impl FnOnce(Value) -> O + Send + Sync  {
-        |response : Value| from_value::<O>(value_mapper(response.get("body").unwrap()).clone()).unwrap()
+    /// Sends a query to its endpoint, mapping the response into the query's response type.
+    ///
+    /// Every endpoint has a matching query builder, e.g. [ChartTracksQuery] or [SubtitleQuery], which is
+    /// validated with [Query::build] before it can be sent.
+    ///
+    /// A body that cannot be mapped into the response type is passed to the error resolver as
+    /// [RequestError::InvalidJsonBody], like any other failed request.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The validated query to send.
+    pub async fn request<Q : Query>(&self,query : Validated<Q>) -> Option<Q::Response> {
+        let query = query.into_inner();
+        let message : Value = self.get_request_handler(Q::ENDPOINT,query.parameters(),|mut response : Value| response["message"].take(),&self.error_resolver).await?;
+        query.response(&message).map_err(|error| (self.error_resolver)(RequestError::InvalidJsonBody(error))).ok()
+    }
+
+    /// Validates `query` with [Query::build] and sends it, see [MusixAbgleich::request].
+    ///
+    /// A [ValidationError](crate::ValidationError) is passed to the error resolver as a bad request payload instead of being sent.
+    pub(crate) async fn send<Q : Query>(&self,query : Q) -> Option<Q::Response> {
+        match query.build() {
+            Ok(query) => self.request(query).await,
+            Err(error) => {
+                (self.error_resolver)(RequestError::ErrorPayload(json!({ "message" : { "header" : { "status_code" : 400, "hint" : error.to_string() }, "body" : "" } })));
+                None
+            },
+        }
     }
 
     /// Retrieves the top artists by country.
     ///
     /// # Arguments
     ///
-    /// * `country` - A valid country code (default: "US").
+    /// * `country` - The country of the chart (default: [Country::UnitedStates]).
     /// * `page` - The page number for paginated results.
     /// * `page_size` - The page size for paginated results. Range is 1 to 100.
-    pub async fn top_artists_by_country(&self,country : Option<&str>,page : Option<u16>,page_size : Option<u8>) -> Option<Vec<Artist>> {
-        let parameters = HashMap::from(
-            [
-                ("country",Value::from(country)),
-                ("page",Value::from(page)),
-                ("page_size",Value::from(page_size))
-            ]
-        ); 
-        self.get_request_handler("chart.artists.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
+    pub async fn top_artists_by_country(&self,country : Option<Country>,page : Option<u32>,page_size : Option<u8>) -> Option<Page<Artist>> {
+        let query = ChartArtistsQuery::new().country(country).page(page).page_size(page_size.map(u32::from));
+        self.send(query).await
     }
 
     /// Retrieves the top tracks by country.
     ///
     /// # Arguments
     ///
-    /// * `country` - A valid 2-letter country code (default: "US"). Set "XW" for worldwide.
+    /// * `country` - The country of the chart (default: [Country::UnitedStates]). Set [Country::Worldwide] for worldwide.
     /// * `chart_name` - Select among available charts: "top" (editorial chart), "hot" (most viewed lyrics in the last 2 hours),
     ///   "mxmweekly" (most viewed lyrics in the last 7 days), "mxmweekly_new" (most viewed lyrics in the last 7 days limited to new releases only).
     /// * `has_lyrics` - When set, filter only contents with lyrics.
     /// * `page` - The page number for paginated results.
     /// * `page_size` - The page size for paginated results. Range is 1 to 100.
-    pub async fn top_tracks_by_country(&self,country : Option<&str>,chart_name : Option<Chart>,has_lyrics : Option<bool>,page : Option<u16>,page_size : Option<u8>) -> Option<Vec<Track>> {
-        let parameters = HashMap::from(
-            [
-                ("country",Value::from(country)),
-                ("chart_name",Value::from(chart_name)),
-                ("f_has_lyrics",Value::from(has_lyrics)),
-                ("page",Value::from(page)),
-                ("page_size",Value::from(page_size))
-            ]
-        ); 
-        self.get_request_handler("chart.tracks.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-    /// Match a song against the Musixmatch database.
-    ///
-    /// In some cases, you may already have some information about the track title, artist name, album, etc.
-    /// This method performs the matching process for you in a single call, taking care of the details and providing instant results.
-    ///
-    /// # Parameters
-    ///
-    /// - `title`: Optional. The song title.
-    /// - `artist`: Optional. The song artist.
-    /// - `album`: Optional. The song album.
-    pub async fn track(&self,title : Option<&str>,artist : Option<&str>,album : Option<&str>) -> Option<Track> {
-        let parameters = HashMap::from(
-            [
-                ("q_title",Value::from(title)),
-                ("q_artist",Value::from(artist)),
-                ("q_album",Value::from(album))
-            ]
-        ); 
-        self.get_request_handler("matcher.track.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-    /// Get track information by Musixmatch commontrack_id.
-    ///
-    /// # Arguments
-    ///
-    /// * `id` - The Musixmatch commontrack_id.
-    pub async fn track_with_commontrack_id(&self, id: u32) -> Option<Track> {
-        let parameters = HashMap::from( [ ("commontrack_id", Value::from(id)) ] );
-        self.get_request_handler("track.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-    /// Get track information by ISRC identifier.
-    ///
-    /// # Arguments
-    ///
-    /// * `isrc` - A valid ISRC identifier.
-    pub async fn track_with_track_isrc(&self, isrc: &str) -> Option<Track> {
-        let parameters = HashMap::from([("track_isrc", Value::from(isrc))]);
-        self.get_request_handler("track.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-    /// Get the lyrics for a track based on its ISRC.
-    ///
-    /// If you have the ISRC (International Standard Recording Code) of a track in your catalogue,
-    /// you can use this method to retrieve the lyrics associated with that track.
-    ///
-    /// # Parameters
-    ///
-    /// - `isrc`: The ISRC identifier of the track.
-    pub async fn track_lyrics_with_track_isrc(&self,isrc: &str) -> Option<Lyrics> {
-        let parameters = HashMap::from([("track_isrc",Value::from(isrc))]);
-        self.get_request_handler("matcher.lyrics.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
+    pub async fn top_tracks_by_country(&self,country : Option<Country>,chart_name : Option<Chart>,has_lyrics : Option<bool>,page : Option<u32>,page_size : Option<u8>) -> Option<Page<Track>> {
+        let query = ChartTracksQuery::new().country(country).chart_name(chart_name).has_lyrics(has_lyrics).page(page).page_size(page_size.map(u32::from));
+        self.send(query).await
     }
 
-
-    /// Get the lyrics for a track based on its title and artist.
-    ///
-    /// Use this method to retrieve the lyrics of a track based on its title and artist.
-    ///
-    /// # Parameters
-    ///
-    /// - `title`: Optional. The song title.
-    /// - `artist`: Optional. The song artist.
-    pub async fn track_lyrics(&self,title : Option<&str>,artist : Option<&str>) -> Option<Lyrics> {
-        let parameters = HashMap::from(
-            [
-                ("q_title",Value::from(title)),
-                ("q_artist",Value::from(artist)),
-            ]
-        ); 
-        self.get_request_handler("matcher.lyrics.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
+    /// Resolves a locator to the commontrack id of the track it points to, looking the track up if needed.
+    async fn common_track_id(&self,locator : &TrackLocator) -> Option<CommonTrackId> {
+        match locator {
+            TrackLocator::ByCommonTrackId(id) => Some(*id),
+            locator => self.track(locator.clone()).await.map(|track| *track.common_track_id()),
+        }
     }
 
-    /// Get the lyrics of a track by Musixmatch commontrack_id.
-    ///
-    /// # Arguments
+    /// Get track information.
     ///
-    /// * `id` - The Musixmatch commontrack_id.
-    pub async fn track_lyrics_with_commontrack_id(&self, id: &str) -> Option<Lyrics> {
-        let parameters = HashMap::from([("commontrack_id", Value::from(id))]);
-        self.get_request_handler("track.lyrics.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-    /// Get the lyrics of a track by Musixmatch track_id.
+    /// A [TrackLocator::ByTitleArtist] is matched against the database through `matcher.track.get`,
+    /// every other locator is looked up directly.
     ///
     /// # Arguments
     ///
-    /// * `id` - The Musixmatch track_id.
-    pub async fn track_lyrics_with_track_id(&self, id: &str) -> Option<Lyrics> {
-        let parameters = HashMap::from([("track_id", Value::from(id))]);
-        self.get_request_handler("track.lyrics.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
+    /// * `locator` - The track to look up.
+    pub async fn track(&self,locator : impl Into<TrackLocator>) -> Option<Track> {
+        match locator.into() {
+            TrackLocator::ByTitleArtist { title, artist } => self.send(TrackMatchQuery::new().title(Some(&title)).artist(Some(&artist))).await,
+            locator => self.send(locator.track_query()).await,
+        }
     }
 
-    /// Get a translated lyrics for a given language
+    /// Get the lyrics of a track.
     ///
     /// # Arguments
-    /// * `commontrack_id` : The Musixmatch commontrack id
-    /// * `selected_language` : The language of the translated lyrics (ISO 639-1)  
-    /// * `min_completed` : Teal from 0 to 1. If present, only the tracks with a translation ratio over this specific value, for a given language, are returned Set it to 1 for completed translation only, to 0.7 for a mimimum of 70% complete translation.
-    pub async fn track_lyrics_translations_with_commontrack_id(&self, id: &str,min_completed : Option<f32> /*percent*/,selected_language : Option<&str>/* (ISO 639-1) */) -> Option<Lyrics> {
-        let parameters = HashMap::from(
-            [
-                ("commontrack_id", Value::from(id)),
-                ("min_completed", Value::from(min_completed)),
-                ("selected_language", Value::from(selected_language)),
-
-            ]
-        );
-        self.get_request_handler("track.lyrics.translation.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-    /// Get a translated lyrics for a given language
     ///
-    /// # Arguments
-    /// * `track_id` : The Musixmatch track id
-    /// * `selected_language` : The language of the translated lyrics (ISO 639-1)  
-    /// * `min_completed` : Teal from 0 to 1. If present, only the tracks with a translation ratio over this specific value, for a given language, are returned Set it to 1 for completed translation only, to 0.7 for a mimimum of 70% complete translation.
-    pub async fn track_lyrics_translations_with_track_id(&self, id: &str,min_completed : Option<f32> /*percent*/,selected_language : Option<&str>/* (ISO 639-1) */) -> Option<Lyrics> {
-        let parameters = HashMap::from(
-            [
-                ("track_id", Value::from(id)),
-                ("min_completed", Value::from(min_completed)),
-                ("selected_language", Value::from(selected_language)),
-
-            ]
-        );
-        self.get_request_handler("track.lyrics.translation.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
+    /// * `locator` - The track to get the lyrics of.
+    pub async fn track_lyrics(&self,locator : impl Into<TrackLocator>) -> Option<Lyrics> {
+        let locator = locator.into();
+        let query = match &locator {
+            TrackLocator::ById(id) => LyricsQuery::new().track_id(Some(*id)),
+            TrackLocator::ByCommonTrackId(id) => LyricsQuery::new().commontrack_id(Some(*id)),
+            TrackLocator::ByIsrc(isrc) => return self.send(LyricsMatchQuery::new().track_isrc(Some(isrc))).await,
+            TrackLocator::ByTitleArtist { title, artist } => return self.send(LyricsMatchQuery::new().title(Some(title)).artist(Some(artist))).await,
+            TrackLocator::ByMbid(_) => LyricsQuery::new().commontrack_id(Some(self.common_track_id(&locator).await?)),
+        };
+        self.send(query).await
     }
 
     /// Get a translated lyrics for a given language
     ///
     /// # Arguments
-    /// * `track_isrc` : A valid ISRC identifier
-    /// * `selected_language` : The language of the translated lyrics (ISO 639-1)  
+    /// * `locator` : The track to get the translated lyrics of
+    /// * `selected_language` : The language of the translated lyrics
     /// * `min_completed` : Teal from 0 to 1. If present, only the tracks with a translation ratio over this specific value, for a given language, are returned Set it to 1 for completed translation only, to 0.7 for a mimimum of 70% complete translation.
-    pub async fn track_lyrics_translations_with_track_irsc(&self, id: &str,min_completed : Option<f32> /*percent*/,selected_language : Option<&str>/* (ISO 639-1) */) -> Option<Lyrics> {
-        let parameters = HashMap::from(
-            [
-                ("track_isrc", Value::from(id)),
-                ("min_completed", Value::from(min_completed)),
-                ("selected_language", Value::from(selected_language)),
-
-            ]
-        );
-        self.get_request_handler("track.lyrics.translation.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
+    pub async fn track_lyrics_translations(&self,locator : impl Into<TrackLocator>,min_completed : Option<f32> /*0 to 1*/,selected_language : Option<&Language>) -> Option<Lyrics> {
+        let locator = locator.into();
+        let query = match &locator {
+            TrackLocator::ById(id) => TranslationQuery::new().track_id(Some(*id)),
+            TrackLocator::ByCommonTrackId(id) => TranslationQuery::new().commontrack_id(Some(*id)),
+            TrackLocator::ByIsrc(isrc) => TranslationQuery::new().track_isrc(Some(isrc)),
+            TrackLocator::ByMbid(mbid) => TranslationQuery::new().track_mbid(Some(mbid)),
+            TrackLocator::ByTitleArtist { .. } => TranslationQuery::new().commontrack_id(Some(self.common_track_id(&locator).await?)),
+        };
+        self.send(query.min_completed(min_completed).selected_language(selected_language)).await
     }
 
-    /// Get a translated lyrics for a given language
+    /// Get the translations of a track's lyrics into each of the given languages, fetched concurrently.
     ///
-    /// # Arguments
-    /// * `track_mbid` : The musicbrainz recording id
-    /// * `selected_language` : The language of the translated lyrics (ISO 639-1)  
-    /// * `min_completed` : Teal from 0 to 1. If present, only the tracks with a translation ratio over this specific value, for a given language, are returned Set it to 1 for completed translation only, to 0.7 for a mimimum of 70% complete translation.
-    pub async fn track_lyrics_translations_with_musixbrainx_id(&self, id: &str,min_completed : Option<f32> /*percent*/,selected_language : Option<&str>/* (ISO 639-1) */) -> Option<Lyrics> {
-        let parameters = HashMap::from(
-            [
-                ("track_mbid", Value::from(id)),
-                ("min_completed", Value::from(min_completed)),
-                ("selected_language", Value::from(selected_language)),
-
-            ]
-        );
-        self.get_request_handler("track.lyrics.translation.get",&parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-
-    /// Get the mood list (and raw value that generated it) of a lyrics by Musixmatch commontrack_id.
+    /// The track is resolved to its commontrack id first, then the lyrics and every translation are requested at once.
+    /// Languages without a translation at least `min_completed` complete are left out of the set.
+    /// Each kept translation reports its own completeness, see [TranslatedLyrics::completeness](crate::TranslatedLyrics::completeness).
+    /// Returns `None` if the track has no lyrics.
     ///
     /// # Arguments
-    ///
-    /// * `id` - The Musixmatch commontrack_id.
-    pub async fn track_lyrics_mood_with_commontrack_id(&self, id: &str) -> Option<Lyrics> {
-        let parameters = HashMap::from([("commontrack_id", Value::from(id))]);
-        self.get_request_handler("track.lyrics.mood.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-    /// Get the mood list (and raw value that generated it) of a lyrics by track ISRC.
+    /// * `locator` : The track to get the translated lyrics of
+    /// * `languages` : The languages to look for translations into
+    /// * `min_completed` : A value from 0 to 1, the minimum translation ratio, as in [MusixAbgleich::track_lyrics_translations].
+    pub async fn track_lyrics_translation_set(&self,locator : impl Into<TrackLocator>,languages : &[Language],min_completed : Option<f32> /*0 to 1*/) -> Option<TranslationSet> {
+        let locator = match locator.into() {
+            locator @ (TrackLocator::ByCommonTrackId(_) | TrackLocator::ByIsrc(_)) => locator,
+            locator => TrackLocator::ByCommonTrackId(self.common_track_id(&locator).await?),
+        };
+        let translations = languages.iter().map(|language| async {
+            (language.clone(),self.track_lyrics_translations(locator.clone(),min_completed,Some(language)).await)
+        });
+        let (original,translations) = futures::join!(self.track_lyrics(locator.clone()),futures::future::join_all(translations));
+        Some(TranslationSet::new(original?,translations))
+    }
+
+    /// Get the mood list (and raw value that generated it) of a lyrics.
     ///
     /// # Arguments
     ///
-    /// * `isrc` - A valid ISRC identifier.
-    pub async fn track_lyrics_mood_with_track_isrc(&self, isrc: &str) -> Option<LyricMood> {
-        let parameters = HashMap::from([("track_isrc", Value::from(isrc))]);
-        self.get_request_handler("track.lyrics.mood.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    /// * `locator` - The track to get the mood of.
+    pub async fn track_lyrics_mood(&self,locator : impl Into<TrackLocator>) -> Option<LyricMood> {
+        let locator = locator.into();
+        let query = match &locator {
+            TrackLocator::ByIsrc(isrc) => MoodQuery::new().track_isrc(Some(isrc)),
+            _ => MoodQuery::new().commontrack_id(Some(self.common_track_id(&locator).await?)),
+        };
+        self.send(query).await
     }
 
-
     /// Get the snippet for a given track.
     ///
     /// A lyrics snippet is a very short representation of a song lyrics.
@@ -325,40 +276,44 @@ impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send
     ///
     /// # Parameters
     ///
-    /// - `track_id`: The musiXmatch track ID.
-    pub async fn track_snippet(&self, track_id: u32) -> Option<Snippet> {
-        let parameters = HashMap::from([("track_id", Value::from(track_id))]);
-        self.get_request_handler("track.snippet.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    /// - `locator`: The track to get the snippet of.
+    pub async fn track_snippet(&self,locator : impl Into<TrackLocator>) -> Option<Snippet> {
+        let track_id = match locator.into() {
+            TrackLocator::ById(id) => id,
+            locator => *self.track(locator).await?.id(),
+        };
+        self.send(SnippetQuery::new(track_id)).await
     }
 
-
     /// Retrieve the subtitle of a track.
     ///
-    /// This method returns the subtitle of a track in LRC or DFXP format.
+    /// This method returns the subtitle of a track in LRC, DFXP or mxm format.
     /// You can specify the desired length of the subtitle and the maximum deviation allowed.
     ///
     /// # Parameters
     ///
-    /// - `commontrack_id`: The Musixmatch commontrack id.
+    /// - `locator`: The track to get the subtitle of.
     /// - `subtitle_length`: Optional. The desired length of the subtitle in seconds.
     /// - `max_deviation`: Optional. The maximum deviation allowed from the desired subtitle length in seconds.
-    /// - `format`: Optional. The format of the subtitle (LRC, DFXP, STLEDU). Defaults to LRC.
-    pub async fn track_subtitle(&self,commontrack_id : u32,subtitle_length/*seconds*/ : Option<u16>,max_deviation : Option<u8> /*seconds*/,format : Option<SubtitleFormat>) -> Option<Subtitle> {
-        let parameters = HashMap::from(
-            [
-                ("commontrack_id", Value::from(commontrack_id)),
-                ("f_subtitle_length", Value::from(subtitle_length)),
-                ("f_subtitle_length_max_deviation", Value::from(max_deviation)),
-                ("subtitle_format", Value::from(format))
-            ]
-        );
-        self.get_request_handler("tracks.subtitle.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
+    /// - `format`: Optional. The format of the subtitle (LRC, DFXP, STLEDU, MXM). Defaults to LRC.
+    pub async fn track_subtitle(&self,locator : impl Into<TrackLocator>,subtitle_length/*seconds*/ : Option<u16>,max_deviation : Option<u8> /*seconds*/,format : Option<SubtitleFormat>) -> Option<Subtitle> {
+        let locator = locator.into();
+        if let TrackLocator::ByTitleArtist { title, artist } = &locator {
+            let query = SubtitleMatchQuery::new().title(Some(title)).artist(Some(artist)).subtitle_length(subtitle_length).max_deviation(max_deviation).format(format);
+            return self.send(query).await
+        }
 
+        let query = SubtitleQuery::new(self.common_track_id(&locator).await?).subtitle_length(subtitle_length).max_deviation(max_deviation).format(format);
+        self.send(query).await
+    }
 
     /// Get the subtitles for a song given its title, artist, and duration.
     ///
     /// You can use the `f_subtitle_length_max_deviation` parameter to fetch subtitles within a given duration range.
+    /// The returned [MatchedSubtitle] holds both the subtitle and the metadata of a track. The subtitle does not say which
+    /// track it belongs to, so the track is a separate best-effort match on `title`, `artist` and `album` alone that ignores
+    /// `subtitle_length` and `max_deviation`, and may be another version of the song than the subtitle's.
+    /// The track is `None` when that match fails, the subtitle is still returned.
     ///
     /// IMPORTANT NOTICE:
     /// You have to include one of the two available tracking systems in your page/application:
@@ -368,6 +323,7 @@ impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send
     /// 2. Image pixel
     ///    Include the URL returned in the `pixel_tracking_url` field as an image src when it's not possible to use the script:
     ///    `<img src="http://tracking.musixmatch.com/t1.0/AMa6hJCIEzn1v8RuXW">`
+    ///
     /// Furthermore, every time a subtitle is present in a page, the `lyrics_copyright` field must also be clearly visible.
     ///
     /// # Parameters
@@ -377,61 +333,54 @@ impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send
     /// - `album`: Optional. The song album.
     /// - `subtitle_length`: Optional. Filter by subtitle length in seconds.
     /// - `max_deviation`: Optional. Max deviation for a subtitle length in seconds.
-    pub async fn subtitle(&self,title : Option<&str>,artist : Option<&str>,album : Option<&str>,subtitle_length/*seconds*/ : Option<u16>,max_deviation : Option<u8> /*seconds*/) -> Option<Track> {
-        let parameters = HashMap::from(
-            [
-                ("q_title",Value::from(title)),
-                ("q_artist",Value::from(artist)),
-                ("q_album",Value::from(album)),
-                ("f_subtitle_length", Value::from(subtitle_length)),
-                ("f_subtitle_length_max_deviation", Value::from(max_deviation)),
-            ]
-        ); 
-
-        self.get_request_handler("matcher.subtitle.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    /// - `format`: Optional. The format of the subtitle (LRC, DFXP, STLEDU, MXM). Defaults to LRC.
+    pub async fn subtitle(&self,title : Option<&str>,artist : Option<&str>,album : Option<&str>,subtitle_length/*seconds*/ : Option<u16>,max_deviation : Option<u8> /*seconds*/,format : Option<SubtitleFormat>) -> Option<MatchedSubtitle> {
+        let query = SubtitleMatchQuery::new().title(title).artist(artist).album(album).subtitle_length(subtitle_length).max_deviation(max_deviation).format(format);
+        let subtitle = self.send(query).await?;
+        let track = self.send(TrackMatchQuery::new().title(title).artist(artist).album(album)).await;
+
+        Some(MatchedSubtitle { subtitle, track })
     }
     
     /// Get a translated subtitle for a given language.
     ///
     /// # Parameters
-    /// `selected_language`: The language of the translated lyrics (ISO 639-1).
+    /// `locator`: The track to get the translated subtitle of.
+    /// `selected_language`: The language of the translated lyrics.
     /// `min_completed`: A value between 0 and 1. If present, only the tracks with a translation ratio over this specific value, for a given language, are returned. Set it to 1 for completed translation only, or to 0.7 for a minimum of 70% complete translation.
-    /// `commontrack_id`: The Musixmatch commontrack ID.
     /// `f_subtitle_length`: The desired length of the subtitle in seconds.
     /// `f_subtitle_length_max_deviation`: The maximum deviation allowed from the f_subtitle_length in seconds.
-    pub async fn track_subtitle_translations_with_commontrack_id(&self, id: &str,min_completed : Option<f32> /*percent*/,selected_language : Option<&str>/* (ISO 639-1) */,subtitle_length/*seconds*/ : Option<u16>,max_deviation : Option<u8> /*seconds*/)-> Option<Subtitle> {
-        let parameters = HashMap::from(
-            [
-                ("commontrack_id", Value::from(id)),
-                ("min_completed", Value::from(min_completed)),
-                ("selected_language", Value::from(selected_language)),
-                ("f_subtitle_length", Value::from(subtitle_length)),
-                ("f_subtitle_length_max_deviation", Value::from(max_deviation)),
-
-            ]
-        );
-        self.get_request_handler("track.subtitle.translation.get", &parameters,Self::create_map(|value| value.get("subtitle_translated").unwrap()),&self.error_resolver).await
+    pub async fn track_subtitle_translations(&self,locator : impl Into<TrackLocator>,min_completed : Option<f32> /*0 to 1*/,selected_language : Option<&Language>,subtitle_length/*seconds*/ : Option<u16>,max_deviation : Option<u8> /*seconds*/) -> Option<Subtitle> {
+        let locator = locator.into();
+        let query = match &locator {
+            TrackLocator::ByIsrc(isrc) => SubtitleTranslationQuery::new().track_isrc(Some(isrc)),
+            _ => SubtitleTranslationQuery::new().commontrack_id(Some(self.common_track_id(&locator).await?)),
+        };
+        let query = query.min_completed(min_completed).selected_language(selected_language).subtitle_length(subtitle_length).max_deviation(max_deviation);
+        self.send(query).await
     }
 
-    /// Get a translated subtitle for a given language.
+    /// Get the subtitle of a track together with its translation, paired cue by cue.
+    ///
+    /// The track is resolved to its commontrack id first, then both subtitles are requested at once. A missing or unreadable translation gives cues without translations,
+    /// see [BilingualLyrics::completeness].
     ///
     /// # Parameters
-    /// `selected_language`: The language of the translated lyrics (ISO 639-1).
-    /// `min_completed`: A value between 0 and 1. If present, only the tracks with a translation ratio over this specific value, for a given language, are returned. Set it to 1 for completed translation only, or to 0.7 for a minimum of 70% complete translation.
-    /// `track_isrc`: A valid ISRC identifier.
+    /// `locator`: The track to get the subtitles of.
+    /// `selected_language`: The language of the translation.
     /// `f_subtitle_length`: The desired length of the subtitle in seconds.
     /// `f_subtitle_length_max_deviation`: The maximum deviation allowed from the f_subtitle_length in seconds.
-    pub async fn track_subtitle_translations_with_track_isrc(&self, id: &str,min_completed : Option<f32> /*percent*/,selected_language : Option<&str>/* (ISO 639-1) */,subtitle_length/*seconds*/ : Option<u16>,max_deviation : Option<u8> /*seconds*/) -> Option<Subtitle> {
-        let parameters = HashMap::from(
-            [
-                ("track_isrc", Value::from(id)),
-                ("min_completed", Value::from(min_completed)),
-                ("selected_language", Value::from(selected_language)),
-                ("f_subtitle_length", Value::from(subtitle_length)),
-                ("f_subtitle_length_max_deviation", Value::from(max_deviation)),
-            ]
+    pub async fn track_bilingual_subtitle(&self,locator : impl Into<TrackLocator>,selected_language : &Language,subtitle_length/*seconds*/ : Option<u16>,max_deviation : Option<u8> /*seconds*/) -> Option<BilingualLyrics> {
+        let locator = match locator.into() {
+            locator @ (TrackLocator::ByCommonTrackId(_) | TrackLocator::ByIsrc(_)) => locator,
+            locator => TrackLocator::ByCommonTrackId(self.common_track_id(&locator).await?),
+        };
+        let (original,translation) = futures::join!(
+            self.track_subtitle(locator.clone(),subtitle_length,max_deviation,None),
+            self.track_subtitle_translations(locator,None,Some(selected_language),subtitle_length,max_deviation),
         );
-        self.get_request_handler("track.subtitle.translation.get", &parameters,Self::create_map(|value| value.get("subtitle_translated").unwrap()),&self.error_resolver).await
+        let translation = translation.and_then(|translation| translation.synced_lyrics().ok()).unwrap_or_default();
+        Some(BilingualLyrics::new(&original?.synced_lyrics().ok()?,&translation))
     }
 
     /// Search for artists in our database.
@@ -443,120 +392,49 @@ impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send
     /// `f_artist_mbid` : When set, filter by this artist musicbrainz id
     /// `page` : Define the page number for paginated results
     /// `page_size` :Define the page size for paginated results. Range is 1 to 100.
-    pub async fn search_artist(&self, artist_song: Option<&str>, artist_id: Option<u32>, artist_mbid: Option<&str>, page: Option<u32>, page_size: Option<u8>) -> Option<Artist> {
-        let parameters = HashMap::from(
-            [
-                ("q_artist", Value::from(artist_song)),
-                ("f_artist_id", Value::from(artist_id)),
-                ("f_artist_mbid", Value::from(artist_mbid)),
-                ("page", Value::from(page)),
-                ("page_size", Value::from(page_size))
-            ]
-        );
-        self.get_request_handler("artist.search", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    pub async fn search_artist(&self, artist_song: Option<&str>, artist_id: Option<ArtistId>, artist_mbid: Option<&Mbid>, page: Option<u32>, page_size: Option<u8>) -> Option<Page<Artist>> {
+        let query = ArtistSearchQuery::new().artist_name(artist_song).filter_artist_id(artist_id).filter_artist_mbid(artist_mbid).page(page).page_size(page_size.map(u32::from));
+        self.send(query).await
     }
     
 
-    /// Get the artist data from the Musixmatch database using the Musixmatch artist ID.
-    ///
-    /// # Parameters
-    ///
-    /// - `id`: The Musixmatch artist ID
-    pub async fn artist_with_musixmatch_id(&self,id : u32) -> Option<Artist> {
-        let parameters = HashMap::from([("artist_id",Value::from(id))]);
-        self.get_request_handler("artist.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-    /// Get the artist data from the Musixmatch database using the Musicbrainz artist ID.
+    /// Get the artist data from the Musixmatch database.
     ///
     /// # Parameters
     ///
-    /// - `id`: The Musicbrainz artist ID.
-    pub async fn artist_with_musixbrainz_id(&self,id : u32) -> Option<Artist> {
-        let parameters = HashMap::from([("artist_mbid",Value::from(id))]);
-        self.get_request_handler("artist.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    /// - `locator`: The Musixmatch or MusicBrainz id of the artist.
+    pub async fn artist(&self,locator : impl Into<ArtistLocator>) -> Option<Artist> {
+        self.send(locator.into().artist_query()).await
     }
 
     /// Get the album discography of an artist
     /// 
     /// # Parameters
     /// 
-    /// artist_id : Musixmatch artist id
+    /// locator : The Musixmatch or MusicBrainz id of the artist
     /// g_album_name : Group by Album Name
     /// s_release_date : Sort by release date (asc|desc)
     /// page : Define the page number for paginated results
     /// page_size : Define the page size for paginated results. Range is 1 to 100.
-    pub async fn artist_relating_albums_with_id(&self,id:u32,album_name: Option<bool>,release_date_sort: Option<SortBy>, page: Option<u32>, page_size: Option<u8>) -> Option<Vec<Album>> {
-        let parameters = HashMap::from([
-            ("artist_id", Value::from(id)),
-            ("g_album_name", Value::from(album_name)),
-            ("s_release_date", Value::from(release_date_sort)),
-            ("page", Value::from(page)),
-            ("page_size", Value::from(page_size)),
-        ]);
-    
-        self.get_request_handler("artist.albums.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    pub async fn artist_relating_albums(&self,locator : impl Into<ArtistLocator>,album_name: Option<bool>,release_date_sort: Option<SortBy>, page: Option<u32>, page_size: Option<u8>) -> Option<Page<Album>> {
+        let query = locator.into().albums_query();
+        let query = query.group_by_album_name(album_name).release_date_sort(release_date_sort).page(page).page_size(page_size.map(u32::from));
+        self.send(query).await
     }
 
-    /// Get the album discography of an artist
-    /// 
-    /// # Parameters
-    /// 
-    /// artist_mbid : Musicbrainz artist id
-    /// g_album_name : Group by Album Name
-    /// s_release_date : Sort by release date (asc|desc)
-    /// page : Define the page number for paginated results
-    /// page_size : Define the page size for paginated results. Range is 1 to 100. 
-    pub async fn artist_relating_albums_with_musixbrainz_id(&self,id:u32,album_name: Option<bool>,release_date_sort: Option<SortBy>, page: Option<u32>, page_size: Option<u8>) -> Option<Vec<Album>> {
-        let parameters = HashMap::from([
-            ("artist_mbid", Value::from(id)),
-            ("g_album_name", Value::from(album_name)),
-            ("s_release_date", Value::from(release_date_sort)),
-            ("page", Value::from(page)),
-            ("page_size", Value::from(page_size)),
-        ]);
-    
-        self.get_request_handler("artist.albums.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
- 
-
     /// Get a list of artists somehow related to a given one.
     /// 
     /// # Parameters
     /// 
-    /// `artist_id` : The musiXmatch artist id
+    /// `locator` : The Musixmatch or MusicBrainz id of the artist
     /// `page` : Define the page number for paginated results
     /// `page_size` : Define the page size for paginated results. Range is 1 to 100
-    pub async fn artist_relating_artist_with_id(&self,id:u32,page: Option<u32>, page_size: Option<u8>) -> Option<Vec<Artist>> {
-        let parameters = HashMap::from([
-            ("artist_id", Value::from(id)),
-            ("page", Value::from(page)),
-            ("page_size", Value::from(page_size)),
-        ]);
-    
-        self.get_request_handler("artist.related.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    pub async fn artist_relating_artist(&self,locator : impl Into<ArtistLocator>,page: Option<u32>, page_size: Option<u8>) -> Option<Page<Artist>> {
+        let query = locator.into().related_artists_query();
+        let query = query.page(page).page_size(page_size.map(u32::from));
+        self.send(query).await
     }
 
-    /// Get a list of artists somehow related to a given one.
-    /// 
-    /// # Parameters
-    /// 
-    /// `artist_mbid` : The musicbrainz artist id
-    /// `page` : Define the page number for paginated results
-    /// `page_size` : Define the page size for paginated results. Range is 1 to 100
-    pub async fn artist_relating_artist_with_musixbrainz_id(&self,id:u32,page: Option<u32>, page_size: Option<u8>) -> Option<Vec<Artist>> {
-        let parameters = HashMap::from([
-            ("artist_mbid", Value::from(id)),
-            ("page", Value::from(page)),
-            ("page_size", Value::from(page_size)),
-        ]);
-    
-        self.get_request_handler("artist.related.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
- 
-
     /// Get an album from the Musixmatch database.
     ///
     /// Retrieves information about an album including its name, release date, release type,
@@ -565,57 +443,29 @@ impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send
     /// # Parameters
     ///
     /// - `id`: The Musixmatch album ID.
-    pub async fn album(&self,id : u32) -> Option<Album> {
-        let parameters = HashMap::from([("album_id",Value::from(id))]);
-        self.get_request_handler("album.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    pub async fn album(&self,id : AlbumId) -> Option<Album> {
+        let query = AlbumQuery::new(id);
+        self.send(query).await
     }
 
     /// This api provides you the list of the songs of an album.
     /// 
     /// # Parameters
     /// 
-    /// album_id : Musixmatch album id
+    /// locator : The Musixmatch or MusicBrainz id of the album
     /// has_lyrics :When set, filter only contents with lyrics
     /// page : Define the page number for paginated results
     /// page_size : Define the page size for paginated results. Range is 1 to 100.
-    pub async fn album_tracks_with_id(&self,id: u32,has_lyrics: Option<bool>, page: Option<u32>, page_size: Option<u8>) -> Option<Vec<Track>> {
-        let parameters = HashMap::from([
-            ("album_id", Value::from(id)),
-            ("f_has_lyrics", Value::from(has_lyrics)),
-            ("page", Value::from(page)),
-            ("page_size", Value::from(page_size)),
-        ]);
-
-        self.get_request_handler("album.tracks.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+    pub async fn album_tracks(&self,locator : impl Into<AlbumLocator>,has_lyrics: Option<bool>, page: Option<u32>, page_size: Option<u8>) -> Option<Page<Track>> {
+        let query = locator.into().tracks_query();
+        let query = query.has_lyrics(has_lyrics).page(page).page_size(page_size.map(u32::from));
+        self.send(query).await
     }
 
-    
-    /// This api provides you the list of the songs of an album.
-    /// 
-    /// # Parameters
-    /// 
-    /// album_mbid : Musicbrainz album id
-    /// has_lyrics :When set, filter only contents with lyrics
-    /// page : Define the page number for paginated results
-    /// page_size : Define the page size for paginated results. Range is 1 to 100.
-    pub async fn album_tracks_with_musixbrainz_id(&self,id: u32 ,has_lyrics: Option<bool>, page: Option<u32>, page_size: Option<u8>) -> Option<Vec<Track>> {
-        let parameters = HashMap::from([
-            ("album_mbid", Value::from(id)),
-            ("f_has_lyrics", Value::from(has_lyrics)),
-            ("page", Value::from(page)),
-            ("page_size", Value::from(page_size)),
-        ]);
-
-        self.get_request_handler("album.tracks.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
-    }
-
-
- 
-
     /// Get the list of music genres in the catalogue.
     pub async fn genres(&self) -> Option<Vec<Genre>> {
-        let parameters = HashMap::new();
-        self.get_request_handler("music.genres.get", &parameters,Self::create_map(|value| value),&self.error_resolver).await
+        let query = GenresQuery::new();
+        self.send(query).await
     }
 
  
@@ -632,33 +482,39 @@ impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send
     /// 
     /// `domain` : Your domain name
     pub async fn tracking_url(&self,domain : &str) -> Option<String> {
-        let parameters = HashMap::from([("domain",Value::from(domain))]);
-        
-        self.get_request_handler("tracking.url.get", &parameters,Self::create_map(|value| value.get("url").unwrap()),&self.error_resolver).await
+        let query = TrackingUrlQuery::new(domain);
+        self.send(query).await
     }
 
     /// Search for track in our database.
     /// 
     /// # Parameters
     /// 
-    /// `q_track` : The song title
-    /// `q_artist` : The song artist
-    /// `q_lyrics` : Any word in the lyrics
-    /// `q_track_artist` : Any word in the song title or artist name
-    /// `q_writer` : Search among writers
-    /// `q` : Any word in the song title or artist name or lyrics
-    /// `f_artist_id` : When set, filter by this artist id
-    /// `f_music_genre_i`d : When set, filter by this music category id
-    /// `f_lyrics_language` : Filter by the lyrics language (en,it,..)
-    /// `f_has_lyrics` : When set, filter only contents with lyrics
-    /// `f_track_release_group_first_release_date_min` : When set, filter the tracks with release date newer than value, format is YYYYMMDD
-    /// `f_track_release_group_first_release_date_max` : When set, filter the tracks with release date older than value, format is YYYYMMDD
-    /// `s_artist_rating` : Sort by our popularity index for artists (asc|desc)
-    /// `s_track_rating` : Sort by our popularity index for tracks (asc|desc)
-    /// `quorum_factor` : Search only a part of the given query string.Allowed range is (0.1 – 0.9)
-    /// `page` : Define the page number for paginated results
-    /// `page_size` : Define the page size for paginated results. Range is 1 to 100.
-    pub async fn search_track(&self,query : TrackSearchQuery) -> Option<Track> {
-        self.get_request_handler("track.search", &query.0,Self::create_map(|value| value),&self.error_resolver).await
+    /// `query` : The search, built and checked with [TrackSearchQuery::build], see the [TrackSearchQuery] builder.
+    pub async fn search_track(&self,query : Validated<TrackSearchQuery>) -> Option<Page<Track>> {
+        self.request(query).await
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn invalid_parameters_reach_the_error_resolver() {
+        let errors = Mutex::new(Vec::new());
+        let client = MusixAbgleich::new("key",|error| match error {
+            RequestError::ErrorPayload(payload) => errors.lock().unwrap().push(payload),
+            _ => panic!("expected an error payload"),
+        });
+
+        let lyrics = futures::executor::block_on(client.track_lyrics_translations(CommonTrackId(1),Some(1.5),Some(&Language::French)));
+        assert_eq!(lyrics, None);
+
+        let errors = errors.into_inner().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0]["message"]["header"]["status_code"], 400);
+        assert!(errors[0]["message"]["header"]["hint"].as_str().is_some_and(|hint| hint.contains("min_completed")));
+    }
+}
@@ -0,0 +1,161 @@
+use std::time::Duration;
+
+use crate::{SyncedLine, SyncedLyrics};
+
+/// A change of the current line reported by [LyricsCursor::advance].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CursorEvent<'l> {
+    /// Playback reached the start of a line. Moving past several lines at once reports each of them in order.
+    Entered {
+        /// The index of the line in [SyncedLyrics::lines].
+        index : usize,
+        /// The line.
+        line : &'l SyncedLine,
+    },
+    /// The current line ended before the next one started, which happens with lines that have an end time.
+    Left {
+        /// The index of the line in [SyncedLyrics::lines].
+        index : usize,
+        /// The line.
+        line : &'l SyncedLine,
+    },
+    /// Playback moved backwards and the cursor jumped to the line now playing, if any.
+    Seeked {
+        /// The index of the current line in [SyncedLyrics::lines].
+        index : Option<usize>,
+    },
+}
+
+/// Follows the playback position through synced lyrics, telling which line is being sung.
+///
+/// A line lasts until its end time if it has one, otherwise until the next line starts. The last line lasts
+/// until the `length` tag, or forever without it. Every lookup is a binary search over the lines.
+#[derive(Debug, Clone)]
+pub struct LyricsCursor<'l> {
+    lines : &'l [SyncedLine],
+    length : Option<Duration>,
+    position : Option<Duration>,
+}
+
+impl SyncedLyrics {
+    /// Creates a cursor standing before the start of the song.
+    pub fn cursor(&self) -> LyricsCursor<'_> {
+        LyricsCursor { lines : self.lines(), length : self.length(), position : None }
+    }
+}
+
+impl<'l> LyricsCursor<'l> {
+    /// The playback position, `None` until the cursor is first moved.
+    pub fn position(&self) -> Option<Duration> {
+        self.position
+    }
+
+    /// The number of lines starting at or before `time`.
+    fn started(&self,time : Duration) -> usize {
+        self.lines.partition_point(|line| *line.time() <= time)
+    }
+
+    /// The time the line at `index` ends at, if known.
+    fn end(&self,index : usize) -> Option<Duration> {
+        let line = &self.lines[index];
+        line.end()
+            .or_else(|| self.lines[index + 1..].iter().map(|next| *next.time()).find(|next| next > line.time()))
+            .or(self.length)
+    }
+
+    /// The index of the line being sung at `time`.
+    fn index_at(&self,time : Duration) -> Option<usize> {
+        let index = self.started(time).checked_sub(1)?;
+        match self.end(index) {
+            Some(end) if end <= time => None,
+            _ => Some(index),
+        }
+    }
+
+    /// Moves the cursor to `time`, forwards or backwards, returning the line being sung then.
+    pub fn seek(&mut self,time : Duration) -> Option<&'l SyncedLine> {
+        self.position = Some(time);
+        self.current_line()
+    }
+
+    /// Moves the cursor to `time`, returning how the current line changed on the way.
+    ///
+    /// Moving forwards reports every line starting after the previous position and up to `time`, so lines are not
+    /// missed between two slow updates. Moving backwards is reported as a single [CursorEvent::Seeked].
+    pub fn advance(&mut self,time : Duration) -> Vec<CursorEvent<'l>> {
+        let (previous,previous_index) = (self.position,self.current_index());
+        let _ = self.seek(time);
+
+        let first = match previous {
+            Some(previous) if time < previous => return vec![CursorEvent::Seeked { index : self.current_index() }],
+            Some(previous) => self.started(previous),
+            None => 0,
+        };
+        let last = self.started(time);
+
+        let mut events : Vec<_> = (first..last).map(|index| CursorEvent::Entered { index, line : &self.lines[index] }).collect();
+        let latest = last.checked_sub(1).filter(|last| *last >= first).or(previous_index);
+        if let (None,Some(index)) = (self.current_index(),latest) {
+            events.push(CursorEvent::Left { index, line : &self.lines[index] });
+        }
+        events
+    }
+
+    /// The index of the line being sung, in [SyncedLyrics::lines].
+    pub fn current_index(&self) -> Option<usize> {
+        self.index_at(self.position?)
+    }
+
+    /// The line being sung, `None` before the first line and between lines that have end times.
+    pub fn current_line(&self) -> Option<&'l SyncedLine> {
+        Some(&self.lines[self.current_index()?])
+    }
+
+    /// The first line starting after the playback position.
+    pub fn next_line(&self) -> Option<&'l SyncedLine> {
+        let started = self.position.map_or(0,|position| self.started(position));
+        self.lines.get(started)
+    }
+
+    /// How far through the current line playback is, from 0 to 1. `None` without a current line or if its end is unknown.
+    pub fn progress(&self) -> Option<f64> {
+        let (position,index) = (self.position?,self.current_index()?);
+        let start = *self.lines[index].time();
+        let length = self.end(index)?.checked_sub(start).filter(|length| !length.is_zero())?;
+        Some(((position - start).as_secs_f64() / length.as_secs_f64()).min(1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_playback() {
+        let mut lyrics = SyncedLyrics::from_lrc("[00:01.00]a\n[00:02.00]b\n[00:03.00]c\n[00:10.00]d");
+        lyrics.push_line(SyncedLine::new(Duration::from_secs(4), "e").with_end(Duration::from_secs(6)));
+        let lines = lyrics.lines();
+        let mut cursor = lyrics.cursor();
+
+        assert_eq!(cursor.current_line(), None);
+        assert_eq!(cursor.next_line(), Some(&lines[0]));
+        assert_eq!(cursor.advance(Duration::from_millis(500)), []);
+
+        assert_eq!(cursor.advance(Duration::from_millis(2_500)), [
+            CursorEvent::Entered { index : 0, line : &lines[0] },
+            CursorEvent::Entered { index : 1, line : &lines[1] },
+        ]);
+        assert_eq!(cursor.current_line().map(SyncedLine::text).map(String::as_str), Some("b"));
+        assert_eq!(cursor.next_line(), Some(&lines[2]));
+        assert_eq!(cursor.progress(), Some(0.5));
+
+        assert_eq!(cursor.advance(Duration::from_secs(5)), [CursorEvent::Entered { index : 2, line : &lines[2] }, CursorEvent::Entered { index : 3, line : &lines[3] }]);
+        assert_eq!(cursor.advance(Duration::from_secs(7)), [CursorEvent::Left { index : 3, line : &lines[3] }]);
+        assert_eq!(cursor.current_line(), None);
+        assert_eq!(cursor.advance(Duration::from_secs(11)), [CursorEvent::Entered { index : 4, line : &lines[4] }]);
+        assert_eq!(cursor.progress(), None);
+
+        assert_eq!(cursor.advance(Duration::from_millis(1_200)), [CursorEvent::Seeked { index : Some(0) }]);
+        assert_eq!(cursor.seek(Duration::ZERO), None);
+    }
+}
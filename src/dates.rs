@@ -0,0 +1,496 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use api_request_utils::{
+    serde_json::Value,
+    serde::{Deserialize, Serialize},
+};
+
+use crate::DateError;
+
+const SECONDS_PER_DAY : i64 = 86_400;
+
+/// Whether `year` is a leap year in the proleptic Gregorian calendar.
+#[allow(clippy::manual_is_multiple_of)] // `is_multiple_of` needs Rust 1.87
+fn is_leap_year(year : u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// The number of days in the given month of the given year.
+fn days_in_month(year : u16,month : u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The number of days between 1970-01-01 and the given date.
+fn days_from_civil(year : u16,month : u8,day : u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The date lying the given number of days after 1970-01-01.
+fn civil_from_days(days : i64) -> (i64,u8,u8) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u8;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u8;
+    (year_of_era + era * 400 + i64::from(month <= 2),month,day)
+}
+
+/// Parses a run of ASCII digits, rejecting signs and whitespace which `str::parse` would accept.
+fn digits<T : FromStr>(s : &str) -> Option<T> {
+    match !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        true => s.parse().ok(),
+        false => None,
+    }
+}
+
+/// A calendar date whose month and day may be unknown, as found in release dates and artist activity dates.
+///
+/// The API reports unknown parts as zeros, so `2004-00-00` parses to a date holding only the year.
+/// `Display` and serde use `YYYY`, `YYYY-MM` or `YYYY-MM-DD` depending on the known precision.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(Deserialize, Serialize)]
+#[serde(crate = "api_request_utils::serde", try_from = "String", into = "String")] // must be below the derive attribute
+pub struct PartialDate {
+    year : u16,
+    month : Option<u8>,
+    day : Option<u8>,
+}
+
+impl PartialDate {
+    /// Creates a date of which only the year is known. The year must lie between 1 and 9999.
+    pub fn from_year(year : u16) -> Option<Self> {
+        (1..=9999).contains(&year).then_some(PartialDate { year, month : None, day : None })
+    }
+
+    /// Creates a date of which only the year and month are known.
+    pub fn from_year_month(year : u16,month : u8) -> Option<Self> {
+        let date = Self::from_year(year)?;
+        (1..=12).contains(&month).then_some(PartialDate { month : Some(month), ..date })
+    }
+
+    /// Creates a date of which the year, month and day are known.
+    pub fn from_ymd(year : u16,month : u8,day : u8) -> Option<Self> {
+        let date = Self::from_year_month(year,month)?;
+        (1..=days_in_month(year,month)).contains(&day).then_some(PartialDate { day : Some(day), ..date })
+    }
+
+    /// The year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The month from 1 to 12, if known.
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    /// The day of the month, if known.
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// Whether the year, month and day are all known.
+    pub fn is_complete(&self) -> bool {
+        self.day.is_some()
+    }
+
+    /// The first day of the period covered by this date, e.g. `2004-01-01` for `2004`.
+    pub fn first_day(&self) -> (u16,u8,u8) {
+        (self.year,self.month.unwrap_or(1),self.day.unwrap_or(1))
+    }
+
+    /// The last day of the period covered by this date, e.g. `2004-02-29` for `2004-02`.
+    pub fn last_day(&self) -> (u16,u8,u8) {
+        let month = self.month.unwrap_or(12);
+        (self.year,month,self.day.unwrap_or_else(|| days_in_month(self.year,month)))
+    }
+
+    /// Formats a day as the `YYYYMMDD` value expected by the search filters.
+    pub(crate) fn format_compact((year,month,day) : (u16,u8,u8)) -> String {
+        format!("{year:04}{month:02}{day:02}")
+    }
+}
+
+impl Display for PartialDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f,"{:04}",self.year)?;
+        if let Some(month) = self.month {
+            write!(f,"-{month:02}")?;
+        }
+        if let Some(day) = self.day {
+            write!(f,"-{day:02}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PartialDate {
+    type Err = DateError;
+
+    /// Parses `YYYY`, `YYYY-MM`, `YYYY-MM-DD` or `YYYYMMDD`, treating zero months and days as unknown.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || DateError::InvalidDate(s.to_owned());
+        let value = s.trim();
+
+        let parts : Vec<&str> = match value.len() {
+            8 if !value.contains('-') => vec![&value[..4],&value[4..6],&value[6..]],
+            _ => value.split('-').collect(),
+        };
+
+        let (year,month,day) = match parts.as_slice() {
+            [year] => (year,"0","0"),
+            [year,month] => (year,*month,"0"),
+            [year,month,day] => (year,*month,*day),
+            _ => return Err(error()),
+        };
+
+        let year = digits(year).filter(|_| year.len() == 4).ok_or_else(error)?;
+        let month = digits(month).filter(|_| month.len() <= 2).ok_or_else(error)?;
+        let day = digits(day).filter(|_| day.len() <= 2).ok_or_else(error)?;
+
+        match (month,day) {
+            (0,0) => Self::from_year(year),
+            (0,_) => None,
+            (month,0) => Self::from_year_month(year,month),
+            (month,day) => Self::from_ymd(year,month,day),
+        }.ok_or_else(error)
+    }
+}
+
+impl TryFrom<String> for PartialDate {
+    type Error = DateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<PartialDate> for String {
+    fn from(date: PartialDate) -> Self {
+        date.to_string()
+    }
+}
+
+/// A point in time in UTC, with a precision of one second.
+///
+/// Parsing accepts RFC 3339 timestamps such as `2016-04-14T13:48:22Z` or `2016-04-14T15:48:22+02:00`;
+/// `Display` and serde use the `Z` form.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(Deserialize, Serialize)]
+#[serde(crate = "api_request_utils::serde", try_from = "String", into = "String")] // must be below the derive attribute
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    const MIN : i64 = -62_135_596_800; // 0001-01-01T00:00:00Z
+    const MAX : i64 = 253_402_300_799; // 9999-12-31T23:59:59Z
+
+    /// Creates a timestamp from the number of seconds since the Unix epoch.
+    ///
+    /// Returns `None` outside of the years 1 to 9999.
+    pub fn from_unix(seconds : i64) -> Option<Self> {
+        (Self::MIN..=Self::MAX).contains(&seconds).then_some(Timestamp(seconds))
+    }
+
+    /// The number of seconds since the Unix epoch.
+    pub fn unix(self) -> i64 {
+        self.0
+    }
+
+    /// The calendar date of this timestamp.
+    pub fn date(self) -> PartialDate {
+        let (year,month,day) = civil_from_days(self.0.div_euclid(SECONDS_PER_DAY));
+        PartialDate { year : year as u16, month : Some(month), day : Some(day) }
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let (year,month,day) = self.date().first_day();
+        let seconds = self.0.rem_euclid(SECONDS_PER_DAY);
+        write!(f,"{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",seconds / 3600,seconds / 60 % 60,seconds % 60)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || DateError::InvalidTimestamp(s.to_owned());
+        let value = s.trim();
+
+        let (date,time) = value.split_once(['T','t',' ']).ok_or_else(error)?;
+        let date : PartialDate = date.parse().ok().filter(|date : &PartialDate| date.is_complete() && date.to_string().len() == 10).ok_or_else(error)?;
+
+        let (time,offset) = match time.strip_suffix(['Z','z']) {
+            Some(time) => (time,0),
+            None => {
+                let (time,offset) = time.split_at(time.rfind(['+','-']).ok_or_else(error)?);
+                let (sign,offset) = offset.split_at(1);
+                let (hours,minutes) = offset.split_once(':').ok_or_else(error)?;
+                let hours = digits::<i64>(hours).filter(|h| *h < 24).ok_or_else(error)?;
+                let minutes = digits::<i64>(minutes).filter(|m| *m < 60).ok_or_else(error)?;
+                let offset = (hours * 60 + minutes) * 60;
+                (time,if sign == "-" { -offset } else { offset })
+            }
+        };
+
+        // Fractional seconds are dropped.
+        let time = time.split_once('.').map_or(time,|(time,_)| time);
+        let [hours,minutes,seconds] = <[&str;3]>::try_from(time.split(':').collect::<Vec<_>>()).map_err(|_| error())?;
+        let hours = digits::<i64>(hours).filter(|h| *h < 24).ok_or_else(error)?;
+        let minutes = digits::<i64>(minutes).filter(|m| *m < 60).ok_or_else(error)?;
+        let seconds = digits::<i64>(seconds).filter(|s| *s <= 60).ok_or_else(error)?;
+
+        let (year,month,day) = date.first_day();
+        let seconds = days_from_civil(year,month,day) * SECONDS_PER_DAY + hours * 3600 + minutes * 60 + seconds.min(59) - offset;
+        Timestamp::from_unix(seconds).ok_or_else(error)
+    }
+}
+
+impl TryFrom<String> for Timestamp {
+    type Error = DateError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.to_string()
+    }
+}
+
+impl From<Timestamp> for Value {
+    fn from(timestamp: Timestamp) -> Self {
+        Value::from(timestamp.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        chrono::DateTime::from_timestamp(timestamp.0,0).expect("timestamps lie within the years 1 to 9999")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = DateError;
+
+    fn try_from(value: chrono::DateTime<chrono::Utc>) -> Result<Self, Self::Error> {
+        Timestamp::from_unix(value.timestamp()).ok_or_else(|| DateError::InvalidTimestamp(format!("{value:?}")))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<PartialDate> for chrono::NaiveDate {
+    type Error = DateError;
+
+    /// Fails unless the year, month and day are all known.
+    fn try_from(value: PartialDate) -> Result<Self, Self::Error> {
+        let (Some(month),Some(day)) = (value.month,value.day) else {
+            return Err(DateError::InvalidDate(value.to_string()))
+        };
+        chrono::NaiveDate::from_ymd_opt(i32::from(value.year),u32::from(month),u32::from(day)).ok_or_else(|| DateError::InvalidDate(value.to_string()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for PartialDate {
+    type Error = DateError;
+
+    fn try_from(value: chrono::NaiveDate) -> Result<Self, Self::Error> {
+        use chrono::Datelike;
+        u16::try_from(value.year()).ok()
+            .and_then(|year| PartialDate::from_ymd(year,value.month() as u8,value.day() as u8))
+            .ok_or_else(|| DateError::InvalidDate(format!("{value:?}")))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        time::OffsetDateTime::from_unix_timestamp(timestamp.0).expect("timestamps lie within the years 1 to 9999")
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = DateError;
+
+    fn try_from(value: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        Timestamp::from_unix(value.unix_timestamp()).ok_or_else(|| DateError::InvalidTimestamp(format!("{value:?}")))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<PartialDate> for time::Date {
+    type Error = DateError;
+
+    /// Fails unless the year, month and day are all known.
+    fn try_from(value: PartialDate) -> Result<Self, Self::Error> {
+        let (Some(month),Some(day)) = (value.month,value.day) else {
+            return Err(DateError::InvalidDate(value.to_string()))
+        };
+        time::Month::try_from(month).ok()
+            .and_then(|month| time::Date::from_calendar_date(i32::from(value.year),month,day).ok())
+            .ok_or_else(|| DateError::InvalidDate(value.to_string()))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::Date> for PartialDate {
+    type Error = DateError;
+
+    fn try_from(value: time::Date) -> Result<Self, Self::Error> {
+        u16::try_from(value.year()).ok()
+            .and_then(|year| PartialDate::from_ymd(year,u8::from(value.month()),value.day()))
+            .ok_or_else(|| DateError::InvalidDate(format!("{value:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_years_follow_the_gregorian_rules() {
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2004));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2023));
+
+        assert!(PartialDate::from_ymd(2000,2,29).is_some());
+        assert!(PartialDate::from_ymd(2004,2,29).is_some());
+        assert!(PartialDate::from_ymd(1900,2,29).is_none());
+        assert!(PartialDate::from_ymd(2023,2,29).is_none());
+        assert!(PartialDate::from_ymd(2023,4,31).is_none());
+        assert_eq!("1900-02-29".parse::<PartialDate>(), Err(DateError::InvalidDate("1900-02-29".to_owned())));
+    }
+
+    #[test]
+    fn partial_dates_cover_their_period() {
+        assert_eq!(PartialDate::from_year_month(2000,2).unwrap().last_day(), (2000,2,29));
+        assert_eq!(PartialDate::from_year_month(1900,2).unwrap().last_day(), (1900,2,28));
+        assert_eq!(PartialDate::from_year_month(2023,2).unwrap().last_day(), (2023,2,28));
+        assert_eq!(PartialDate::from_year(2004).unwrap().first_day(), (2004,1,1));
+        assert_eq!(PartialDate::from_year(2004).unwrap().last_day(), (2004,12,31));
+        assert_eq!(PartialDate::from_ymd(2004,6,15).unwrap().last_day(), (2004,6,15));
+    }
+
+    #[test]
+    fn parses_partial_dates() {
+        let year_only : PartialDate = "2004-00-00".parse().unwrap();
+        assert_eq!((year_only.year(), year_only.month(), year_only.day()), (2004, None, None));
+        assert_eq!(year_only.to_string(), "2004");
+        assert_eq!("2004-02".parse::<PartialDate>().unwrap().to_string(), "2004-02");
+        assert_eq!("20040229".parse::<PartialDate>(), Ok(PartialDate::from_ymd(2004,2,29).unwrap()));
+        assert!("2004-13".parse::<PartialDate>().is_err());
+        assert_eq!("2004-00-05".parse::<PartialDate>(), Err(DateError::InvalidDate("2004-00-05".to_owned())));
+        assert!("204-01-01".parse::<PartialDate>().is_err());
+        assert!("2004-+1-01".parse::<PartialDate>().is_err());
+        assert!("0000".parse::<PartialDate>().is_err());
+    }
+
+    #[test]
+    fn civil_days_round_trip() {
+        assert_eq!(days_from_civil(1970,1,1), 0);
+        assert_eq!(days_from_civil(2000,3,1), 11_017);
+        assert_eq!(days_from_civil(1900,3,1), -25_508);
+        assert_eq!(days_from_civil(1,1,1), -719_162);
+
+        // Walk every day from 1896 to 2104, across the 1900, 2000 and 2100 rules.
+        let mut days = days_from_civil(1896,1,1);
+        for year in 1896..=2104 {
+            for month in 1..=12 {
+                for day in 1..=days_in_month(year,month) {
+                    assert_eq!(days_from_civil(year,month,day), days);
+                    assert_eq!(civil_from_days(days), (i64::from(year),month,day));
+                    days += 1;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parses_rfc3339_timestamps() {
+        let timestamp : Timestamp = "2016-04-14T13:48:22Z".parse().unwrap();
+        assert_eq!(timestamp.unix(), 1_460_641_702);
+        assert_eq!(timestamp.to_string(), "2016-04-14T13:48:22Z");
+        assert_eq!(timestamp.date(), PartialDate::from_ymd(2016,4,14).unwrap());
+
+        assert_eq!("2016-04-14T15:48:22+02:00".parse(), Ok(timestamp));
+        assert_eq!("2016-04-14T08:18:22-05:30".parse(), Ok(timestamp));
+        assert_eq!("2016-04-15T00:48:22+11:00".parse(), Ok(timestamp));
+        assert_eq!("2016-04-14t13:48:22.250z".parse(), Ok(timestamp));
+        assert_eq!("2016-04-14 13:48:22+00:00".parse(), Ok(timestamp));
+        assert_eq!("1969-12-31T23:59:59Z".parse::<Timestamp>().map(Timestamp::unix), Ok(-1));
+
+        for invalid in ["2016-04-14T13:48:22", "2016-04-14T13:48:22+0200", "2016-04-14T24:00:00Z", "2016-04T13:48:22Z", "2016-04-14T13:48Z", "2016-04-14T13:48:22+24:00"] {
+            assert_eq!(invalid.parse::<Timestamp>(), Err(DateError::InvalidTimestamp(invalid.to_owned())));
+        }
+    }
+
+    #[test]
+    fn timestamps_stay_within_years_1_to_9999() {
+        assert_eq!("0001-01-01T00:00:00Z".parse::<Timestamp>().map(Timestamp::unix), Ok(Timestamp::MIN));
+        assert_eq!("9999-12-31T23:59:59Z".parse::<Timestamp>().map(Timestamp::unix), Ok(Timestamp::MAX));
+        assert!("0001-01-01T00:00:00+01:00".parse::<Timestamp>().is_err());
+        assert!("9999-12-31T23:59:59-01:00".parse::<Timestamp>().is_err());
+        assert_eq!(Timestamp::from_unix(Timestamp::MIN - 1), None);
+        assert_eq!(Timestamp::from_unix(Timestamp::MAX).map(|timestamp| timestamp.to_string()).as_deref(), Some("9999-12-31T23:59:59Z"));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn converts_to_and_from_chrono() {
+        for seconds in (Timestamp::MIN..=Timestamp::MAX).step_by(7_777_777).chain([Timestamp::MAX, 951_782_400]) {
+            let timestamp = Timestamp::from_unix(seconds).unwrap();
+            let converted = chrono::DateTime::<chrono::Utc>::from(timestamp);
+            assert_eq!(converted.timestamp(), seconds);
+            assert_eq!(Timestamp::try_from(converted), Ok(timestamp));
+
+            let date = chrono::NaiveDate::try_from(timestamp.date()).unwrap();
+            assert_eq!(date, converted.date_naive());
+            assert_eq!(PartialDate::try_from(date), Ok(timestamp.date()));
+        }
+
+        assert!(chrono::NaiveDate::try_from(PartialDate::from_year_month(2000,2).unwrap()).is_err());
+        assert!(PartialDate::try_from(chrono::NaiveDate::from_ymd_opt(10_000,1,1).unwrap()).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn converts_to_and_from_time() {
+        for seconds in (Timestamp::MIN..=Timestamp::MAX).step_by(7_777_777).chain([Timestamp::MAX, 951_782_400]) {
+            let timestamp = Timestamp::from_unix(seconds).unwrap();
+            let converted = time::OffsetDateTime::from(timestamp);
+            assert_eq!(converted.unix_timestamp(), seconds);
+            assert_eq!(Timestamp::try_from(converted), Ok(timestamp));
+
+            let date = time::Date::try_from(timestamp.date()).unwrap();
+            assert_eq!(date, converted.date());
+            assert_eq!(PartialDate::try_from(date), Ok(timestamp.date()));
+        }
+
+        assert!(time::Date::try_from(PartialDate::from_year(2000).unwrap()).is_err());
+    }
+}
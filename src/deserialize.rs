@@ -0,0 +1,53 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+};
+
+use api_request_utils::serde::{Deserialize, Deserializer};
+
+/// Deserializes an optional value, treating the empty string sent by the API for missing values as `None`.
+///
+/// Any other value that does not parse is an error.
+pub(crate) fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error> where D : Deserializer<'de>, T : FromStr, T::Err : Display {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(value) if !value.trim().is_empty() => value.parse().map(Some).map_err(api_request_utils::serde::de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+/// Deserializes an optional value, treating the empty string and values this crate does not know about as `None`.
+pub(crate) fn unknown_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error> where D : Deserializer<'de>, T : FromStr {
+    Ok(Option::<String>::deserialize(deserializer)?.and_then(|value| value.trim().parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use api_request_utils::serde_json::{self, json};
+
+    use super::*;
+    use crate::Mbid;
+
+    #[derive(Deserialize, Debug)]
+    #[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+    struct Ids {
+        #[serde(default, deserialize_with = "empty_as_none")]
+        strict : Option<Mbid>,
+        #[serde(default, deserialize_with = "unknown_as_none")]
+        lenient : Option<Mbid>,
+    }
+
+    #[test]
+    fn empty_and_unknown_values_are_none() {
+        let ids : Ids = serde_json::from_value(json!({ "strict" : "", "lenient" : "not an mbid" })).unwrap();
+        assert_eq!((ids.strict, ids.lenient), (None, None));
+
+        let ids : Ids = serde_json::from_value(json!({})).unwrap();
+        assert_eq!((ids.strict, ids.lenient), (None, None));
+
+        let ids : Ids = serde_json::from_value(json!({ "strict" : " 056e4f3e-d505-4dad-8ec1-d04f521cbb56 ", "lenient" : "056e4f3e-d505-4dad-8ec1-d04f521cbb56" })).unwrap();
+        assert_eq!(ids.strict, ids.lenient);
+        assert!(ids.strict.is_some());
+
+        assert!(serde_json::from_value::<Ids>(json!({ "strict" : "not an mbid" })).is_err());
+    }
+}
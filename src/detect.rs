@@ -0,0 +1,77 @@
+use getset::Getters;
+use whatlang::Lang;
+
+use crate::{CleanLyrics, Language, Lyrics};
+
+/// The language a text was detected to be in, see [detect_language].
+#[derive(Getters)]
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct DetectedLanguage {
+    /// The detected language.
+    #[getset(get = "pub")]
+    language : Language,
+
+    /// How sure the detector is, from 0 to 1.
+    #[getset(get = "pub")]
+    confidence : f64,
+
+    /// Whether the detector considers the result reliable, which needs both a clear winner and enough text.
+    #[getset(get = "pub")]
+    is_reliable : bool,
+}
+
+/// The ISO 639-1 code of a language the detector knows.
+fn code(lang : Lang) -> &'static str {
+    match lang {
+        Lang::Epo => "eo", Lang::Eng => "en", Lang::Rus => "ru", Lang::Cmn => "zh", Lang::Spa => "es", Lang::Por => "pt",
+        Lang::Ita => "it", Lang::Ben => "bn", Lang::Fra => "fr", Lang::Deu => "de", Lang::Ukr => "uk", Lang::Kat => "ka",
+        Lang::Ara => "ar", Lang::Hin => "hi", Lang::Jpn => "ja", Lang::Heb => "he", Lang::Yid => "yi", Lang::Pol => "pl",
+        Lang::Amh => "am", Lang::Jav => "jv", Lang::Kor => "ko", Lang::Nob => "nb", Lang::Dan => "da", Lang::Swe => "sv",
+        Lang::Fin => "fi", Lang::Tur => "tr", Lang::Nld => "nl", Lang::Hun => "hu", Lang::Ces => "cs", Lang::Ell => "el",
+        Lang::Bul => "bg", Lang::Bel => "be", Lang::Mar => "mr", Lang::Kan => "kn", Lang::Ron => "ro", Lang::Slv => "sl",
+        Lang::Hrv => "hr", Lang::Srp => "sr", Lang::Mkd => "mk", Lang::Lit => "lt", Lang::Lav => "lv", Lang::Est => "et",
+        Lang::Tam => "ta", Lang::Vie => "vi", Lang::Urd => "ur", Lang::Tha => "th", Lang::Guj => "gu", Lang::Uzb => "uz",
+        Lang::Pan => "pa", Lang::Aze => "az", Lang::Ind => "id", Lang::Tel => "te", Lang::Pes => "fa", Lang::Mal => "ml",
+        Lang::Ori => "or", Lang::Mya => "my", Lang::Nep => "ne", Lang::Sin => "si", Lang::Khm => "km", Lang::Tuk => "tk",
+        Lang::Aka => "ak", Lang::Zul => "zu", Lang::Sna => "sn", Lang::Afr => "af", Lang::Lat => "la", Lang::Slk => "sk",
+        Lang::Cat => "ca", Lang::Tgl => "tl", Lang::Hye => "hy",
+    }
+}
+
+/// Detects the language of `text` locally, from its script and the trigrams of its words, without calling the API.
+///
+/// Returns `None` if the text gives nothing to go on, e.g. when it is empty or only numbers and punctuation. Short
+/// texts give unreliable results, so check [DetectedLanguage::is_reliable].
+pub fn detect_language(text : &str) -> Option<DetectedLanguage> {
+    let info = whatlang::detect(text)?;
+    Some(DetectedLanguage {
+        language : code(info.lang()).parse().unwrap_or_else(|never| match never {}),
+        confidence : info.confidence(),
+        is_reliable : info.is_reliable(),
+    })
+}
+
+impl Lyrics {
+    /// Detects the language of the lyrics locally, ignoring the commercial use disclaimer, see [detect_language].
+    ///
+    /// Useful where [Lyrics::language] is missing or wrong, as it can be for translated lyrics.
+    pub fn detect_language(&self) -> Option<DetectedLanguage> {
+        detect_language(&CleanLyrics::new(self.lyrics()).text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_languages() {
+        assert!(Lang::all().iter().all(|lang| code(*lang).parse::<Language>().is_ok_and(|language| language.is_known())));
+
+        let english = detect_language("I walked down the street in the evening rain, thinking about the things you said to me and wondering where we went wrong.").unwrap();
+        assert_eq!(english.language(), &Language::English);
+        assert!(*english.confidence() > 0.0 && *english.confidence() <= 1.0);
+        assert_eq!(detect_language("Я иду домой по тёмной улице, и снег падает на землю").map(|detected| detected.language), Some(Language::Russian));
+        assert_eq!(detect_language("1234 !!"), None);
+    }
+}
@@ -0,0 +1,309 @@
+use std::time::Duration;
+
+use roxmltree::{Document, Node};
+
+use crate::{SyncedLine, SyncedLyrics, SyncedLyricsError};
+
+/// The rates needed to resolve frame and tick based time expressions, read from the `tt` element.
+#[derive(Clone, Copy)]
+struct Rates {
+    frame_rate : f64,
+    tick_rate : f64,
+}
+
+impl Rates {
+    fn read(root : Node<'_, '_>) -> Self {
+        let parameter = |name : &str| root.attributes()
+            .find(|attribute| attribute.name() == name)
+            .and_then(|attribute| attribute.value().trim().parse::<f64>().ok())
+            .filter(|rate| *rate > 0.0);
+
+        let multiplier = root.attributes()
+            .find(|attribute| attribute.name() == "frameRateMultiplier")
+            .and_then(|attribute| {
+                let (numerator,denominator) = attribute.value().trim().split_once(char::is_whitespace)?;
+                Some(numerator.parse::<f64>().ok()? / denominator.trim().parse::<f64>().ok().filter(|d| *d > 0.0)?)
+            })
+            .unwrap_or(1.0);
+
+        let frame_rate = parameter("frameRate").unwrap_or(30.0) * multiplier;
+        let tick_rate = parameter("tickRate").unwrap_or(match parameter("frameRate") {
+            Some(_) => frame_rate * parameter("subFrameRate").unwrap_or(1.0),
+            None => 1.0,
+        });
+
+        Rates { frame_rate, tick_rate }
+    }
+}
+
+/// Parses a TTML time expression, either a clock time (`00:01:02.5`, `00:01:02:15`) or an offset time (`62.5s`, `1500ms`, `90f`).
+fn parse_time(value : &str,rates : Rates) -> Option<Duration> {
+    let value = value.trim();
+    let number = |s : &str| -> Option<f64> {
+        let valid = !s.is_empty() && !s.starts_with('.') && !s.ends_with('.') && s.bytes().all(|b| b.is_ascii_digit() || b == b'.') && s.matches('.').count() <= 1;
+        valid.then(|| s.parse().ok()).flatten()
+    };
+
+    let seconds = match value.split(':').collect::<Vec<_>>().as_slice() {
+        [hours,minutes,seconds] if minutes.len() == 2 && seconds.len() >= 2 => {
+            let (minutes,seconds) = (number(minutes).filter(|m| *m < 60.0)?,number(seconds).filter(|s| *s < 61.0)?);
+            number(hours)? * 3600.0 + minutes * 60.0 + seconds
+        },
+        [hours,minutes,seconds,frames] if minutes.len() == 2 && seconds.len() == 2 => {
+            let (minutes,seconds) = (number(minutes).filter(|m| *m < 60.0)?,number(seconds).filter(|s| *s < 61.0)?);
+            number(hours)? * 3600.0 + minutes * 60.0 + seconds + number(frames)? / rates.frame_rate
+        },
+        [offset] => {
+            let at = offset.find(|c : char| c.is_ascii_alphabetic())?;
+            let (count,metric) = offset.split_at(at);
+            let count = number(count)?;
+            match metric {
+                "h" => count * 3600.0,
+                "m" => count * 60.0,
+                "s" => count,
+                "ms" => count / 1000.0,
+                "f" => count / rates.frame_rate,
+                "t" => count / rates.tick_rate,
+                _ => return None,
+            }
+        },
+        _ => return None,
+    };
+
+    // Times that do not fit in a u64 of milliseconds would saturate, so they are rejected.
+    let millis = (seconds * 1000.0).round();
+    (millis.is_finite() && millis < u64::MAX as f64).then(|| Duration::from_millis(millis as u64))
+}
+
+/// The timing inherited from the ancestors of an element.
+#[derive(Clone, Copy)]
+struct Context {
+    offset : Duration,
+    end : Option<Duration>,
+    timed : bool,
+}
+
+/// Reads the `begin` attribute of `node` together with its `end` or `dur`, resolved against `context`.
+fn timing(node : Node<'_, '_>,context : Context,rates : Rates) -> Result<Context,SyncedLyricsError> {
+    let attribute = |name : &str| -> Result<Option<Duration>,SyncedLyricsError> {
+        match node.attribute(name) {
+            Some(value) => parse_time(value,rates).map(Some).ok_or_else(|| SyncedLyricsError::InvalidTime(value.to_owned())),
+            None => Ok(None),
+        }
+    };
+
+    // Times too large to add up are as invalid as unreadable ones.
+    let add = |time : Duration,other : Duration,name : &str| {
+        time.checked_add(other).ok_or_else(|| SyncedLyricsError::InvalidTime(node.attribute(name).unwrap_or_default().to_owned()))
+    };
+
+    let begin = attribute("begin")?;
+    let start = add(context.offset,begin.unwrap_or_default(),"begin")?;
+
+    let end = match (attribute("end")?,attribute("dur")?) {
+        (Some(end),Some(dur)) => Some(add(context.offset,end,"end")?.min(add(start,dur,"dur")?)),
+        (Some(end),None) => Some(add(context.offset,end,"end")?),
+        (None,Some(dur)) => Some(add(start,dur,"dur")?),
+        (None,None) => context.end,
+    };
+
+    // An element never outlasts its parent.
+    let end = match (end,context.end) {
+        (Some(end),Some(parent)) => Some(end.min(parent)),
+        (end,parent) => end.or(parent),
+    };
+
+    Ok(Context { offset : start, end, timed : context.timed || begin.is_some() })
+}
+
+/// Appends the text of `node` to `text`, turning `<br/>` into line breaks.
+fn collect_text(node : Node<'_, '_>,text : &mut String) {
+    for child in node.children() {
+        if child.is_text() {
+            // Line breaks in the source are plain whitespace, only `<br/>` starts a new row.
+            text.extend(child.text().unwrap_or_default().chars().map(|c| if c.is_whitespace() { ' ' } else { c }));
+        } else if child.has_tag_name_local("br") {
+            text.push('\n');
+        } else if child.is_element() && !child.has_tag_name_local("metadata") && !child.has_tag_name_local("set") {
+            collect_text(child,text);
+        }
+    }
+}
+
+/// Collapses runs of whitespace within each row of `text` into single spaces.
+fn normalize(text : &str) -> String {
+    text.split('\n')
+        .map(|row| row.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The earliest `begin` of the spans within a paragraph that has no `begin` of its own.
+fn first_span_begin(node : Node<'_, '_>,context : Context,rates : Rates) -> Result<Option<Duration>,SyncedLyricsError> {
+    let mut first = None;
+    for span in node.descendants().filter(|span| span.has_tag_name_local("span") && span.has_attribute("begin")) {
+        let begin = timing(span,context,rates)?.offset;
+        first = Some(first.map_or(begin,|first : Duration| first.min(begin)));
+    }
+    Ok(first)
+}
+
+fn read_block(node : Node<'_, '_>,context : Context,rates : Rates,lines : &mut Vec<SyncedLine>) -> Result<(),SyncedLyricsError> {
+    for child in node.children().filter(Node::is_element) {
+        if child.has_tag_name_local("p") {
+            let mut timing = timing(child,context,rates)?;
+            if !child.has_attribute("begin") {
+                if let Some(begin) = first_span_begin(child,context,rates)? {
+                    timing = Context { offset : begin, timed : true, ..timing };
+                }
+            }
+            if !timing.timed {
+                continue;
+            }
+
+            let mut text = String::new();
+            collect_text(child,&mut text);
+
+            let line = SyncedLine::new(timing.offset,normalize(&text));
+            lines.push(match timing.end {
+                Some(end) => line.with_end(end.max(timing.offset)),
+                None => line,
+            });
+        } else if child.has_tag_name_local("div") || child.has_tag_name_local("body") {
+            read_block(child,timing(child,context,rates)?,rates,lines)?;
+        }
+    }
+    Ok(())
+}
+
+/// Matches elements by their local name, as DFXP documents use several namespaces for the same elements.
+trait LocalName {
+    fn has_tag_name_local(&self,name : &str) -> bool;
+}
+
+impl LocalName for Node<'_, '_> {
+    fn has_tag_name_local(&self,name : &str) -> bool {
+        self.is_element() && self.tag_name().name() == name
+    }
+}
+
+impl SyncedLyrics {
+    /// Parses a DFXP/TTML subtitle document.
+    ///
+    /// Every timed `<p>` becomes a line. `begin`, `end` and `dur` may be given as clock times (`00:01:02.50`,
+    /// `00:01:02:15` with frames) or offset times (`62.5s`, `1500ms`, `90f`, `1000t`), and are resolved against
+    /// the timing of the enclosing `<body>` and `<div>` elements. A `<p>` without timing of its own starts with
+    /// its enclosing `<div>`, or with its first timed span, and is skipped if nothing around it is timed.
+    ///
+    /// The text of nested spans is joined and `<br/>` separates the rows of a line. The document title
+    /// (`ttm:title`) is kept as the `ti` tag.
+    pub fn from_dfxp(dfxp : &str) -> Result<Self,SyncedLyricsError> {
+        let document = Document::parse(dfxp).map_err(|error| SyncedLyricsError::InvalidDfxp(error.to_string()))?;
+        let root = document.root_element();
+        if !root.has_tag_name_local("tt") {
+            return Err(SyncedLyricsError::InvalidDfxp(format!("expected a `tt` root element, found `{}`",root.tag_name().name())));
+        }
+
+        let rates = Rates::read(root);
+        let mut lyrics = SyncedLyrics::default();
+
+        let title = root.children()
+            .filter(|child| child.has_tag_name_local("head"))
+            .flat_map(|head| head.descendants())
+            .find(|node| node.has_tag_name_local("title"))
+            .and_then(|title| title.text())
+            .map(str::trim)
+            .filter(|title| !title.is_empty());
+        if let Some(title) = title {
+            lyrics.set_tag("ti",title);
+        }
+
+        let context = Context { offset : Duration::ZERO, end : None, timed : false };
+        if let Some(body) = root.children().find(|child| child.has_tag_name_local("body")) {
+            read_block(body,timing(body,context,rates)?,rates,&mut lyrics.lines)?;
+        }
+
+        lyrics.lines.sort_by_key(|line| *line.time());
+        Ok(lyrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DFXP : &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<tt xmlns="http://www.w3.org/ns/ttml" xmlns:ttm="http://www.w3.org/ns/ttml#metadata" xmlns:ttp="http://www.w3.org/ns/ttml#parameter" ttp:frameRate="25" xml:lang="en">
+  <head><metadata><ttm:title>Harder, Better, Faster, Stronger</ttm:title></metadata></head>
+  <body>
+    <div>
+      <p>untimed</p>
+      <p begin="0:00:12.50" end="0:00:13.10">Work it</p>
+      <p begin="13.5s" dur="500ms">Make <span>it,</span>
+         do it</p>
+      <p begin="00:00:15:05" end="00:00:16:00">Makes us<br/><span>harder</span></p>
+    </div>
+    <div begin="60s">
+      <p begin="2s" end="3s">Better</p>
+      <p><span begin="5s">Faster</span></p>
+      <p>Stronger</p>
+    </div>
+  </body>
+</tt>"#;
+
+    #[test]
+    fn parses_timed_paragraphs() {
+        let lyrics = SyncedLyrics::from_dfxp(DFXP).unwrap();
+        let millis = Duration::from_millis;
+
+        assert_eq!(lyrics.title(), Some("Harder, Better, Faster, Stronger"));
+        assert_eq!(lyrics.lines(), &[
+            SyncedLine::new(millis(12_500), "Work it").with_end(millis(13_100)),
+            SyncedLine::new(millis(13_500), "Make it, do it").with_end(millis(14_000)),
+            SyncedLine::new(millis(15_200), "Makes us\nharder").with_end(millis(16_000)),
+            SyncedLine::new(millis(60_000), "Stronger"),
+            SyncedLine::new(millis(62_000), "Better").with_end(millis(63_000)),
+            SyncedLine::new(millis(65_000), "Faster"),
+        ]);
+    }
+
+    #[test]
+    fn parses_time_expressions() {
+        let rates = Rates { frame_rate : 30.0, tick_rate : 10_000.0 };
+        let time = |value : &str| parse_time(value, rates).map(|time| time.as_millis());
+
+        assert_eq!(time("01:02:03"), Some(3_723_000));
+        assert_eq!(time("00:00:01.5"), Some(1_500));
+        assert_eq!(time("00:00:01:15"), Some(1_500));
+        assert_eq!(time("1.5h"), Some(5_400_000));
+        assert_eq!(time("2m"), Some(120_000));
+        assert_eq!(time("250ms"), Some(250));
+        assert_eq!(time("45f"), Some(1_500));
+        assert_eq!(time("15000t"), Some(1_500));
+        assert_eq!(time("12"), None);
+        assert_eq!(time("1.s"), None);
+        assert_eq!(time("00:61:00"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        assert!(matches!(SyncedLyrics::from_dfxp("<tt><body>"), Err(SyncedLyricsError::InvalidDfxp(_))));
+        assert!(matches!(SyncedLyrics::from_dfxp("<html/>"), Err(SyncedLyricsError::InvalidDfxp(_))));
+        assert_eq!(SyncedLyrics::from_dfxp(r#"<tt><body><p begin="soon">x</p></body></tt>"#), Err(SyncedLyricsError::InvalidTime("soon".to_owned())));
+    }
+
+    #[test]
+    fn rejects_times_too_large_for_a_duration() {
+        let rates = Rates { frame_rate : 0.0, tick_rate : 1.0 };
+        assert_eq!(parse_time("18446744073709551s", rates), None);
+        assert_eq!(parse_time("18014398509481984s", rates), Some(Duration::from_secs(18_014_398_509_481_984)));
+        assert_eq!(parse_time("1f", rates), None);
+
+        let huge = "99999999999999999999s";
+        assert_eq!(SyncedLyrics::from_dfxp(&format!(r#"<tt><body><p begin="{huge}">x</p></body></tt>"#)), Err(SyncedLyricsError::InvalidTime(huge.to_owned())));
+
+        let large = "18014398509481984s";
+        let lyrics = SyncedLyrics::from_dfxp(&format!(r#"<tt><body><div begin="{large}"><p begin="{large}" dur="{large}">x</p></div></body></tt>"#)).unwrap();
+        assert_eq!(lyrics.lines()[0].end(), &Some(Duration::from_secs(3 * 18_014_398_509_481_984)));
+    }
+}
@@ -1,7 +1,16 @@
 #![allow(missing_docs)]
 
-use strum::Display;
-use api_request_utils::serde_json::Value;
+use std::{
+    convert::Infallible,
+    fmt::{Display as FmtDisplay, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use strum::{Display, EnumString, EnumMessage, IntoStaticStr, ParseError};
+use api_request_utils::{
+    serde_json::Value,
+    serde::{Deserialize, Serialize},
+};
 
 #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
 #[derive(Display)]
@@ -25,6 +34,9 @@ pub enum SubtitleFormat {
     Dfxp,
     #[strum(serialize = "stledu")]
     Stledu,
+    /// JSON with the time of every line in seconds, see [SyncedLyrics::from_mxm](crate::SyncedLyrics::from_mxm).
+    #[strum(serialize = "mxm")]
+    Mxm,
 }
 
 #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
@@ -36,6 +48,830 @@ pub enum SortBy {
     Desecending,
 }
 
+/// A country as accepted by the chart endpoints: every ISO 3166-1 alpha-2 code plus `XW` for the worldwide chart.
+///
+/// `Display` and serde use the two letter code, parsing ignores case. [Country::name] returns the English name.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+#[derive(Display, EnumString, EnumMessage, IntoStaticStr)]
+#[derive(Deserialize, Serialize)]
+#[strum(ascii_case_insensitive)]
+#[serde(crate = "api_request_utils::serde", try_from = "String", into = "String")] // must be below the derive attribute
+pub enum Country {
+    #[strum(serialize = "AD", message = "Andorra")]
+    Andorra,
+    #[strum(serialize = "AE", message = "United Arab Emirates")]
+    UnitedArabEmirates,
+    #[strum(serialize = "AF", message = "Afghanistan")]
+    Afghanistan,
+    #[strum(serialize = "AG", message = "Antigua and Barbuda")]
+    AntiguaAndBarbuda,
+    #[strum(serialize = "AI", message = "Anguilla")]
+    Anguilla,
+    #[strum(serialize = "AL", message = "Albania")]
+    Albania,
+    #[strum(serialize = "AM", message = "Armenia")]
+    Armenia,
+    #[strum(serialize = "AO", message = "Angola")]
+    Angola,
+    #[strum(serialize = "AQ", message = "Antarctica")]
+    Antarctica,
+    #[strum(serialize = "AR", message = "Argentina")]
+    Argentina,
+    #[strum(serialize = "AS", message = "American Samoa")]
+    AmericanSamoa,
+    #[strum(serialize = "AT", message = "Austria")]
+    Austria,
+    #[strum(serialize = "AU", message = "Australia")]
+    Australia,
+    #[strum(serialize = "AW", message = "Aruba")]
+    Aruba,
+    #[strum(serialize = "AX", message = "Åland Islands")]
+    AlandIslands,
+    #[strum(serialize = "AZ", message = "Azerbaijan")]
+    Azerbaijan,
+    #[strum(serialize = "BA", message = "Bosnia and Herzegovina")]
+    BosniaAndHerzegovina,
+    #[strum(serialize = "BB", message = "Barbados")]
+    Barbados,
+    #[strum(serialize = "BD", message = "Bangladesh")]
+    Bangladesh,
+    #[strum(serialize = "BE", message = "Belgium")]
+    Belgium,
+    #[strum(serialize = "BF", message = "Burkina Faso")]
+    BurkinaFaso,
+    #[strum(serialize = "BG", message = "Bulgaria")]
+    Bulgaria,
+    #[strum(serialize = "BH", message = "Bahrain")]
+    Bahrain,
+    #[strum(serialize = "BI", message = "Burundi")]
+    Burundi,
+    #[strum(serialize = "BJ", message = "Benin")]
+    Benin,
+    #[strum(serialize = "BL", message = "Saint Barthélemy")]
+    SaintBarthelemy,
+    #[strum(serialize = "BM", message = "Bermuda")]
+    Bermuda,
+    #[strum(serialize = "BN", message = "Brunei Darussalam")]
+    BruneiDarussalam,
+    #[strum(serialize = "BO", message = "Bolivia")]
+    Bolivia,
+    #[strum(serialize = "BQ", message = "Bonaire, Sint Eustatius and Saba")]
+    BonaireSintEustatiusAndSaba,
+    #[strum(serialize = "BR", message = "Brazil")]
+    Brazil,
+    #[strum(serialize = "BS", message = "Bahamas")]
+    Bahamas,
+    #[strum(serialize = "BT", message = "Bhutan")]
+    Bhutan,
+    #[strum(serialize = "BV", message = "Bouvet Island")]
+    BouvetIsland,
+    #[strum(serialize = "BW", message = "Botswana")]
+    Botswana,
+    #[strum(serialize = "BY", message = "Belarus")]
+    Belarus,
+    #[strum(serialize = "BZ", message = "Belize")]
+    Belize,
+    #[strum(serialize = "CA", message = "Canada")]
+    Canada,
+    #[strum(serialize = "CC", message = "Cocos (Keeling) Islands")]
+    CocosIslands,
+    #[strum(serialize = "CD", message = "Democratic Republic of the Congo")]
+    DemocraticRepublicOfTheCongo,
+    #[strum(serialize = "CF", message = "Central African Republic")]
+    CentralAfricanRepublic,
+    #[strum(serialize = "CG", message = "Congo")]
+    Congo,
+    #[strum(serialize = "CH", message = "Switzerland")]
+    Switzerland,
+    #[strum(serialize = "CI", message = "Côte d'Ivoire")]
+    CoteDIvoire,
+    #[strum(serialize = "CK", message = "Cook Islands")]
+    CookIslands,
+    #[strum(serialize = "CL", message = "Chile")]
+    Chile,
+    #[strum(serialize = "CM", message = "Cameroon")]
+    Cameroon,
+    #[strum(serialize = "CN", message = "China")]
+    China,
+    #[strum(serialize = "CO", message = "Colombia")]
+    Colombia,
+    #[strum(serialize = "CR", message = "Costa Rica")]
+    CostaRica,
+    #[strum(serialize = "CU", message = "Cuba")]
+    Cuba,
+    #[strum(serialize = "CV", message = "Cabo Verde")]
+    CaboVerde,
+    #[strum(serialize = "CW", message = "Curaçao")]
+    Curacao,
+    #[strum(serialize = "CX", message = "Christmas Island")]
+    ChristmasIsland,
+    #[strum(serialize = "CY", message = "Cyprus")]
+    Cyprus,
+    #[strum(serialize = "CZ", message = "Czechia")]
+    Czechia,
+    #[strum(serialize = "DE", message = "Germany")]
+    Germany,
+    #[strum(serialize = "DJ", message = "Djibouti")]
+    Djibouti,
+    #[strum(serialize = "DK", message = "Denmark")]
+    Denmark,
+    #[strum(serialize = "DM", message = "Dominica")]
+    Dominica,
+    #[strum(serialize = "DO", message = "Dominican Republic")]
+    DominicanRepublic,
+    #[strum(serialize = "DZ", message = "Algeria")]
+    Algeria,
+    #[strum(serialize = "EC", message = "Ecuador")]
+    Ecuador,
+    #[strum(serialize = "EE", message = "Estonia")]
+    Estonia,
+    #[strum(serialize = "EG", message = "Egypt")]
+    Egypt,
+    #[strum(serialize = "EH", message = "Western Sahara")]
+    WesternSahara,
+    #[strum(serialize = "ER", message = "Eritrea")]
+    Eritrea,
+    #[strum(serialize = "ES", message = "Spain")]
+    Spain,
+    #[strum(serialize = "ET", message = "Ethiopia")]
+    Ethiopia,
+    #[strum(serialize = "FI", message = "Finland")]
+    Finland,
+    #[strum(serialize = "FJ", message = "Fiji")]
+    Fiji,
+    #[strum(serialize = "FK", message = "Falkland Islands")]
+    FalklandIslands,
+    #[strum(serialize = "FM", message = "Micronesia")]
+    Micronesia,
+    #[strum(serialize = "FO", message = "Faroe Islands")]
+    FaroeIslands,
+    #[strum(serialize = "FR", message = "France")]
+    France,
+    #[strum(serialize = "GA", message = "Gabon")]
+    Gabon,
+    #[strum(serialize = "GB", message = "United Kingdom")]
+    UnitedKingdom,
+    #[strum(serialize = "GD", message = "Grenada")]
+    Grenada,
+    #[strum(serialize = "GE", message = "Georgia")]
+    Georgia,
+    #[strum(serialize = "GF", message = "French Guiana")]
+    FrenchGuiana,
+    #[strum(serialize = "GG", message = "Guernsey")]
+    Guernsey,
+    #[strum(serialize = "GH", message = "Ghana")]
+    Ghana,
+    #[strum(serialize = "GI", message = "Gibraltar")]
+    Gibraltar,
+    #[strum(serialize = "GL", message = "Greenland")]
+    Greenland,
+    #[strum(serialize = "GM", message = "Gambia")]
+    Gambia,
+    #[strum(serialize = "GN", message = "Guinea")]
+    Guinea,
+    #[strum(serialize = "GP", message = "Guadeloupe")]
+    Guadeloupe,
+    #[strum(serialize = "GQ", message = "Equatorial Guinea")]
+    EquatorialGuinea,
+    #[strum(serialize = "GR", message = "Greece")]
+    Greece,
+    #[strum(serialize = "GS", message = "South Georgia and the South Sandwich Islands")]
+    SouthGeorgiaAndTheSouthSandwichIslands,
+    #[strum(serialize = "GT", message = "Guatemala")]
+    Guatemala,
+    #[strum(serialize = "GU", message = "Guam")]
+    Guam,
+    #[strum(serialize = "GW", message = "Guinea-Bissau")]
+    GuineaBissau,
+    #[strum(serialize = "GY", message = "Guyana")]
+    Guyana,
+    #[strum(serialize = "HK", message = "Hong Kong")]
+    HongKong,
+    #[strum(serialize = "HM", message = "Heard Island and McDonald Islands")]
+    HeardIslandAndMcDonaldIslands,
+    #[strum(serialize = "HN", message = "Honduras")]
+    Honduras,
+    #[strum(serialize = "HR", message = "Croatia")]
+    Croatia,
+    #[strum(serialize = "HT", message = "Haiti")]
+    Haiti,
+    #[strum(serialize = "HU", message = "Hungary")]
+    Hungary,
+    #[strum(serialize = "ID", message = "Indonesia")]
+    Indonesia,
+    #[strum(serialize = "IE", message = "Ireland")]
+    Ireland,
+    #[strum(serialize = "IL", message = "Israel")]
+    Israel,
+    #[strum(serialize = "IM", message = "Isle of Man")]
+    IsleOfMan,
+    #[strum(serialize = "IN", message = "India")]
+    India,
+    #[strum(serialize = "IO", message = "British Indian Ocean Territory")]
+    BritishIndianOceanTerritory,
+    #[strum(serialize = "IQ", message = "Iraq")]
+    Iraq,
+    #[strum(serialize = "IR", message = "Iran")]
+    Iran,
+    #[strum(serialize = "IS", message = "Iceland")]
+    Iceland,
+    #[strum(serialize = "IT", message = "Italy")]
+    Italy,
+    #[strum(serialize = "JE", message = "Jersey")]
+    Jersey,
+    #[strum(serialize = "JM", message = "Jamaica")]
+    Jamaica,
+    #[strum(serialize = "JO", message = "Jordan")]
+    Jordan,
+    #[strum(serialize = "JP", message = "Japan")]
+    Japan,
+    #[strum(serialize = "KE", message = "Kenya")]
+    Kenya,
+    #[strum(serialize = "KG", message = "Kyrgyzstan")]
+    Kyrgyzstan,
+    #[strum(serialize = "KH", message = "Cambodia")]
+    Cambodia,
+    #[strum(serialize = "KI", message = "Kiribati")]
+    Kiribati,
+    #[strum(serialize = "KM", message = "Comoros")]
+    Comoros,
+    #[strum(serialize = "KN", message = "Saint Kitts and Nevis")]
+    SaintKittsAndNevis,
+    #[strum(serialize = "KP", message = "North Korea")]
+    NorthKorea,
+    #[strum(serialize = "KR", message = "South Korea")]
+    SouthKorea,
+    #[strum(serialize = "KW", message = "Kuwait")]
+    Kuwait,
+    #[strum(serialize = "KY", message = "Cayman Islands")]
+    CaymanIslands,
+    #[strum(serialize = "KZ", message = "Kazakhstan")]
+    Kazakhstan,
+    #[strum(serialize = "LA", message = "Laos")]
+    Laos,
+    #[strum(serialize = "LB", message = "Lebanon")]
+    Lebanon,
+    #[strum(serialize = "LC", message = "Saint Lucia")]
+    SaintLucia,
+    #[strum(serialize = "LI", message = "Liechtenstein")]
+    Liechtenstein,
+    #[strum(serialize = "LK", message = "Sri Lanka")]
+    SriLanka,
+    #[strum(serialize = "LR", message = "Liberia")]
+    Liberia,
+    #[strum(serialize = "LS", message = "Lesotho")]
+    Lesotho,
+    #[strum(serialize = "LT", message = "Lithuania")]
+    Lithuania,
+    #[strum(serialize = "LU", message = "Luxembourg")]
+    Luxembourg,
+    #[strum(serialize = "LV", message = "Latvia")]
+    Latvia,
+    #[strum(serialize = "LY", message = "Libya")]
+    Libya,
+    #[strum(serialize = "MA", message = "Morocco")]
+    Morocco,
+    #[strum(serialize = "MC", message = "Monaco")]
+    Monaco,
+    #[strum(serialize = "MD", message = "Moldova")]
+    Moldova,
+    #[strum(serialize = "ME", message = "Montenegro")]
+    Montenegro,
+    #[strum(serialize = "MF", message = "Saint Martin")]
+    SaintMartin,
+    #[strum(serialize = "MG", message = "Madagascar")]
+    Madagascar,
+    #[strum(serialize = "MH", message = "Marshall Islands")]
+    MarshallIslands,
+    #[strum(serialize = "MK", message = "North Macedonia")]
+    NorthMacedonia,
+    #[strum(serialize = "ML", message = "Mali")]
+    Mali,
+    #[strum(serialize = "MM", message = "Myanmar")]
+    Myanmar,
+    #[strum(serialize = "MN", message = "Mongolia")]
+    Mongolia,
+    #[strum(serialize = "MO", message = "Macao")]
+    Macao,
+    #[strum(serialize = "MP", message = "Northern Mariana Islands")]
+    NorthernMarianaIslands,
+    #[strum(serialize = "MQ", message = "Martinique")]
+    Martinique,
+    #[strum(serialize = "MR", message = "Mauritania")]
+    Mauritania,
+    #[strum(serialize = "MS", message = "Montserrat")]
+    Montserrat,
+    #[strum(serialize = "MT", message = "Malta")]
+    Malta,
+    #[strum(serialize = "MU", message = "Mauritius")]
+    Mauritius,
+    #[strum(serialize = "MV", message = "Maldives")]
+    Maldives,
+    #[strum(serialize = "MW", message = "Malawi")]
+    Malawi,
+    #[strum(serialize = "MX", message = "Mexico")]
+    Mexico,
+    #[strum(serialize = "MY", message = "Malaysia")]
+    Malaysia,
+    #[strum(serialize = "MZ", message = "Mozambique")]
+    Mozambique,
+    #[strum(serialize = "NA", message = "Namibia")]
+    Namibia,
+    #[strum(serialize = "NC", message = "New Caledonia")]
+    NewCaledonia,
+    #[strum(serialize = "NE", message = "Niger")]
+    Niger,
+    #[strum(serialize = "NF", message = "Norfolk Island")]
+    NorfolkIsland,
+    #[strum(serialize = "NG", message = "Nigeria")]
+    Nigeria,
+    #[strum(serialize = "NI", message = "Nicaragua")]
+    Nicaragua,
+    #[strum(serialize = "NL", message = "Netherlands")]
+    Netherlands,
+    #[strum(serialize = "NO", message = "Norway")]
+    Norway,
+    #[strum(serialize = "NP", message = "Nepal")]
+    Nepal,
+    #[strum(serialize = "NR", message = "Nauru")]
+    Nauru,
+    #[strum(serialize = "NU", message = "Niue")]
+    Niue,
+    #[strum(serialize = "NZ", message = "New Zealand")]
+    NewZealand,
+    #[strum(serialize = "OM", message = "Oman")]
+    Oman,
+    #[strum(serialize = "PA", message = "Panama")]
+    Panama,
+    #[strum(serialize = "PE", message = "Peru")]
+    Peru,
+    #[strum(serialize = "PF", message = "French Polynesia")]
+    FrenchPolynesia,
+    #[strum(serialize = "PG", message = "Papua New Guinea")]
+    PapuaNewGuinea,
+    #[strum(serialize = "PH", message = "Philippines")]
+    Philippines,
+    #[strum(serialize = "PK", message = "Pakistan")]
+    Pakistan,
+    #[strum(serialize = "PL", message = "Poland")]
+    Poland,
+    #[strum(serialize = "PM", message = "Saint Pierre and Miquelon")]
+    SaintPierreAndMiquelon,
+    #[strum(serialize = "PN", message = "Pitcairn")]
+    Pitcairn,
+    #[strum(serialize = "PR", message = "Puerto Rico")]
+    PuertoRico,
+    #[strum(serialize = "PS", message = "Palestine")]
+    Palestine,
+    #[strum(serialize = "PT", message = "Portugal")]
+    Portugal,
+    #[strum(serialize = "PW", message = "Palau")]
+    Palau,
+    #[strum(serialize = "PY", message = "Paraguay")]
+    Paraguay,
+    #[strum(serialize = "QA", message = "Qatar")]
+    Qatar,
+    #[strum(serialize = "RE", message = "Réunion")]
+    Reunion,
+    #[strum(serialize = "RO", message = "Romania")]
+    Romania,
+    #[strum(serialize = "RS", message = "Serbia")]
+    Serbia,
+    #[strum(serialize = "RU", message = "Russia")]
+    Russia,
+    #[strum(serialize = "RW", message = "Rwanda")]
+    Rwanda,
+    #[strum(serialize = "SA", message = "Saudi Arabia")]
+    SaudiArabia,
+    #[strum(serialize = "SB", message = "Solomon Islands")]
+    SolomonIslands,
+    #[strum(serialize = "SC", message = "Seychelles")]
+    Seychelles,
+    #[strum(serialize = "SD", message = "Sudan")]
+    Sudan,
+    #[strum(serialize = "SE", message = "Sweden")]
+    Sweden,
+    #[strum(serialize = "SG", message = "Singapore")]
+    Singapore,
+    #[strum(serialize = "SH", message = "Saint Helena, Ascension and Tristan da Cunha")]
+    SaintHelenaAscensionAndTristanDaCunha,
+    #[strum(serialize = "SI", message = "Slovenia")]
+    Slovenia,
+    #[strum(serialize = "SJ", message = "Svalbard and Jan Mayen")]
+    SvalbardAndJanMayen,
+    #[strum(serialize = "SK", message = "Slovakia")]
+    Slovakia,
+    #[strum(serialize = "SL", message = "Sierra Leone")]
+    SierraLeone,
+    #[strum(serialize = "SM", message = "San Marino")]
+    SanMarino,
+    #[strum(serialize = "SN", message = "Senegal")]
+    Senegal,
+    #[strum(serialize = "SO", message = "Somalia")]
+    Somalia,
+    #[strum(serialize = "SR", message = "Suriname")]
+    Suriname,
+    #[strum(serialize = "SS", message = "South Sudan")]
+    SouthSudan,
+    #[strum(serialize = "ST", message = "Sao Tome and Principe")]
+    SaoTomeAndPrincipe,
+    #[strum(serialize = "SV", message = "El Salvador")]
+    ElSalvador,
+    #[strum(serialize = "SX", message = "Sint Maarten")]
+    SintMaarten,
+    #[strum(serialize = "SY", message = "Syria")]
+    Syria,
+    #[strum(serialize = "SZ", message = "Eswatini")]
+    Eswatini,
+    #[strum(serialize = "TC", message = "Turks and Caicos Islands")]
+    TurksAndCaicosIslands,
+    #[strum(serialize = "TD", message = "Chad")]
+    Chad,
+    #[strum(serialize = "TF", message = "French Southern Territories")]
+    FrenchSouthernTerritories,
+    #[strum(serialize = "TG", message = "Togo")]
+    Togo,
+    #[strum(serialize = "TH", message = "Thailand")]
+    Thailand,
+    #[strum(serialize = "TJ", message = "Tajikistan")]
+    Tajikistan,
+    #[strum(serialize = "TK", message = "Tokelau")]
+    Tokelau,
+    #[strum(serialize = "TL", message = "Timor-Leste")]
+    TimorLeste,
+    #[strum(serialize = "TM", message = "Turkmenistan")]
+    Turkmenistan,
+    #[strum(serialize = "TN", message = "Tunisia")]
+    Tunisia,
+    #[strum(serialize = "TO", message = "Tonga")]
+    Tonga,
+    #[strum(serialize = "TR", message = "Türkiye")]
+    Turkiye,
+    #[strum(serialize = "TT", message = "Trinidad and Tobago")]
+    TrinidadAndTobago,
+    #[strum(serialize = "TV", message = "Tuvalu")]
+    Tuvalu,
+    #[strum(serialize = "TW", message = "Taiwan")]
+    Taiwan,
+    #[strum(serialize = "TZ", message = "Tanzania")]
+    Tanzania,
+    #[strum(serialize = "UA", message = "Ukraine")]
+    Ukraine,
+    #[strum(serialize = "UG", message = "Uganda")]
+    Uganda,
+    #[strum(serialize = "UM", message = "United States Minor Outlying Islands")]
+    UnitedStatesMinorOutlyingIslands,
+    #[strum(serialize = "US", message = "United States")]
+    UnitedStates,
+    #[strum(serialize = "UY", message = "Uruguay")]
+    Uruguay,
+    #[strum(serialize = "UZ", message = "Uzbekistan")]
+    Uzbekistan,
+    #[strum(serialize = "VA", message = "Holy See")]
+    HolySee,
+    #[strum(serialize = "VC", message = "Saint Vincent and the Grenadines")]
+    SaintVincentAndTheGrenadines,
+    #[strum(serialize = "VE", message = "Venezuela")]
+    Venezuela,
+    #[strum(serialize = "VG", message = "British Virgin Islands")]
+    BritishVirginIslands,
+    #[strum(serialize = "VI", message = "U.S. Virgin Islands")]
+    UnitedStatesVirginIslands,
+    #[strum(serialize = "VN", message = "Viet Nam")]
+    VietNam,
+    #[strum(serialize = "VU", message = "Vanuatu")]
+    Vanuatu,
+    #[strum(serialize = "WF", message = "Wallis and Futuna")]
+    WallisAndFutuna,
+    #[strum(serialize = "WS", message = "Samoa")]
+    Samoa,
+    #[strum(serialize = "YE", message = "Yemen")]
+    Yemen,
+    #[strum(serialize = "YT", message = "Mayotte")]
+    Mayotte,
+    #[strum(serialize = "ZA", message = "South Africa")]
+    SouthAfrica,
+    #[strum(serialize = "ZM", message = "Zambia")]
+    Zambia,
+    #[strum(serialize = "ZW", message = "Zimbabwe")]
+    Zimbabwe,
+    #[strum(serialize = "XW", message = "Worldwide")]
+    Worldwide,
+}
+
+impl Country {
+    /// The two letter code of the country.
+    pub fn code(self) -> &'static str {
+        self.into()
+    }
+
+    /// The English name of the country.
+    pub fn name(self) -> &'static str {
+        self.get_message().unwrap_or_default()
+    }
+}
+
+impl TryFrom<String> for Country {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.trim().parse()
+    }
+}
+
+impl From<Country> for String {
+    fn from(country: Country) -> Self {
+        country.code().to_owned()
+    }
+}
+
+impl From<Country> for Value {
+    fn from(country: Country) -> Self {
+        Value::from(country.code())
+    }
+}
+
+macro_rules! languages {
+    ($($variant:ident => $code:literal, $name:literal, $native:literal;)*) => {
+        /// A language as identified by its ISO 639-1 code, e.g. `en` or `it`.
+        ///
+        /// `Display` and serde use the lowercase code. Parsing ignores case and never fails: codes this crate
+        /// does not know about are kept in [Language::Unknown], so no value returned by the API is lost.
+        #[derive(Clone, PartialEq, PartialOrd, Eq, Ord, Debug, Hash)]
+        #[derive(Deserialize, Serialize)]
+        #[serde(crate = "api_request_utils::serde", from = "String", into = "String")] // must be below the derive attribute
+        pub enum Language {
+            $(
+                #[doc = $name]
+                $variant,
+            )*
+            /// A code that is not part of ISO 639-1, stored in lowercase.
+            Unknown(String),
+        }
+
+        impl Language {
+            /// The ISO 639-1 code of the language.
+            pub fn code(&self) -> &str {
+                match self {
+                    $(Language::$variant => $code,)*
+                    Language::Unknown(code) => code,
+                }
+            }
+
+            /// The English name of the language, or `None` for [Language::Unknown].
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $(Language::$variant => Some($name),)*
+                    Language::Unknown(_) => None,
+                }
+            }
+
+            /// The name of the language in the language itself, or `None` for [Language::Unknown].
+            pub fn native_name(&self) -> Option<&'static str> {
+                match self {
+                    $(Language::$variant => Some($native),)*
+                    Language::Unknown(_) => None,
+                }
+            }
+        }
+
+        impl FromStr for Language {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let code = s.trim().to_ascii_lowercase();
+                Ok(match code.as_str() {
+                    $($code => Language::$variant,)*
+                    _ => Language::Unknown(code),
+                })
+            }
+        }
+    };
+}
+
+languages! {
+    Afar => "aa", "Afar", "Afaraf";
+    Abkhazian => "ab", "Abkhazian", "аҧсуа бызшәа";
+    Avestan => "ae", "Avestan", "avesta";
+    Afrikaans => "af", "Afrikaans", "Afrikaans";
+    Akan => "ak", "Akan", "Akan";
+    Amharic => "am", "Amharic", "አማርኛ";
+    Aragonese => "an", "Aragonese", "aragonés";
+    Arabic => "ar", "Arabic", "العربية";
+    Assamese => "as", "Assamese", "অসমীয়া";
+    Avaric => "av", "Avaric", "авар мацӀ";
+    Aymara => "ay", "Aymara", "aymar aru";
+    Azerbaijani => "az", "Azerbaijani", "azərbaycan dili";
+    Bashkir => "ba", "Bashkir", "башҡорт теле";
+    Belarusian => "be", "Belarusian", "беларуская мова";
+    Bulgarian => "bg", "Bulgarian", "български език";
+    Bislama => "bi", "Bislama", "Bislama";
+    Bambara => "bm", "Bambara", "bamanankan";
+    Bengali => "bn", "Bengali", "বাংলা";
+    Tibetan => "bo", "Tibetan", "བོད་ཡིག";
+    Breton => "br", "Breton", "brezhoneg";
+    Bosnian => "bs", "Bosnian", "bosanski jezik";
+    Catalan => "ca", "Catalan", "català";
+    Chechen => "ce", "Chechen", "нохчийн мотт";
+    Chamorro => "ch", "Chamorro", "Chamoru";
+    Corsican => "co", "Corsican", "corsu";
+    Cree => "cr", "Cree", "ᓀᐦᐃᔭᐍᐏᐣ";
+    Czech => "cs", "Czech", "čeština";
+    ChurchSlavic => "cu", "Church Slavic", "ѩзыкъ словѣньскъ";
+    Chuvash => "cv", "Chuvash", "чӑваш чӗлхи";
+    Welsh => "cy", "Welsh", "Cymraeg";
+    Danish => "da", "Danish", "dansk";
+    German => "de", "German", "Deutsch";
+    Divehi => "dv", "Divehi", "ދިވެހި";
+    Dzongkha => "dz", "Dzongkha", "རྫོང་ཁ";
+    Ewe => "ee", "Ewe", "Eʋegbe";
+    Greek => "el", "Greek", "ελληνικά";
+    English => "en", "English", "English";
+    Esperanto => "eo", "Esperanto", "Esperanto";
+    Spanish => "es", "Spanish", "español";
+    Estonian => "et", "Estonian", "eesti";
+    Basque => "eu", "Basque", "euskara";
+    Persian => "fa", "Persian", "فارسی";
+    Fulah => "ff", "Fulah", "Fulfulde";
+    Finnish => "fi", "Finnish", "suomi";
+    Fijian => "fj", "Fijian", "vosa Vakaviti";
+    Faroese => "fo", "Faroese", "føroyskt";
+    French => "fr", "French", "français";
+    WesternFrisian => "fy", "Western Frisian", "Frysk";
+    Irish => "ga", "Irish", "Gaeilge";
+    ScottishGaelic => "gd", "Scottish Gaelic", "Gàidhlig";
+    Galician => "gl", "Galician", "galego";
+    Guarani => "gn", "Guarani", "Avañe'ẽ";
+    Gujarati => "gu", "Gujarati", "ગુજરાતી";
+    Manx => "gv", "Manx", "Gaelg";
+    Hausa => "ha", "Hausa", "Hausa";
+    Hebrew => "he", "Hebrew", "עברית";
+    Hindi => "hi", "Hindi", "हिन्दी";
+    HiriMotu => "ho", "Hiri Motu", "Hiri Motu";
+    Croatian => "hr", "Croatian", "hrvatski jezik";
+    Haitian => "ht", "Haitian", "Kreyòl ayisyen";
+    Hungarian => "hu", "Hungarian", "magyar";
+    Armenian => "hy", "Armenian", "Հայերեն";
+    Herero => "hz", "Herero", "Otjiherero";
+    Interlingua => "ia", "Interlingua", "Interlingua";
+    Indonesian => "id", "Indonesian", "Bahasa Indonesia";
+    Interlingue => "ie", "Interlingue", "Interlingue";
+    Igbo => "ig", "Igbo", "Asụsụ Igbo";
+    SichuanYi => "ii", "Sichuan Yi", "ꆈꌠ꒿ Nuosuhxop";
+    Inupiaq => "ik", "Inupiaq", "Iñupiaq";
+    Ido => "io", "Ido", "Ido";
+    Icelandic => "is", "Icelandic", "Íslenska";
+    Italian => "it", "Italian", "italiano";
+    Inuktitut => "iu", "Inuktitut", "ᐃᓄᒃᑎᑐᑦ";
+    Japanese => "ja", "Japanese", "日本語";
+    Javanese => "jv", "Javanese", "basa Jawa";
+    Georgian => "ka", "Georgian", "ქართული";
+    Kongo => "kg", "Kongo", "Kikongo";
+    Kikuyu => "ki", "Kikuyu", "Gĩkũyũ";
+    Kuanyama => "kj", "Kuanyama", "Kuanyama";
+    Kazakh => "kk", "Kazakh", "қазақ тілі";
+    Kalaallisut => "kl", "Kalaallisut", "kalaallisut";
+    Khmer => "km", "Khmer", "ខ្មែរ";
+    Kannada => "kn", "Kannada", "ಕನ್ನಡ";
+    Korean => "ko", "Korean", "한국어";
+    Kanuri => "kr", "Kanuri", "Kanuri";
+    Kashmiri => "ks", "Kashmiri", "कश्मीरी";
+    Kurdish => "ku", "Kurdish", "Kurdî";
+    Komi => "kv", "Komi", "коми кыв";
+    Cornish => "kw", "Cornish", "Kernewek";
+    Kirghiz => "ky", "Kirghiz", "Кыргызча";
+    Latin => "la", "Latin", "latine";
+    Luxembourgish => "lb", "Luxembourgish", "Lëtzebuergesch";
+    Ganda => "lg", "Ganda", "Luganda";
+    Limburgish => "li", "Limburgish", "Limburgs";
+    Lingala => "ln", "Lingala", "Lingála";
+    Lao => "lo", "Lao", "ພາສາລາວ";
+    Lithuanian => "lt", "Lithuanian", "lietuvių kalba";
+    LubaKatanga => "lu", "Luba-Katanga", "Kiluba";
+    Latvian => "lv", "Latvian", "latviešu valoda";
+    Malagasy => "mg", "Malagasy", "fiteny malagasy";
+    Marshallese => "mh", "Marshallese", "Kajin M̧ajeļ";
+    Maori => "mi", "Maori", "te reo Māori";
+    Macedonian => "mk", "Macedonian", "македонски јазик";
+    Malayalam => "ml", "Malayalam", "മലയാളം";
+    Mongolian => "mn", "Mongolian", "Монгол хэл";
+    Marathi => "mr", "Marathi", "मराठी";
+    Malay => "ms", "Malay", "Bahasa Melayu";
+    Maltese => "mt", "Maltese", "Malti";
+    Burmese => "my", "Burmese", "ဗမာစာ";
+    Nauru => "na", "Nauru", "Dorerin Naoero";
+    NorwegianBokmal => "nb", "Norwegian Bokmål", "Norsk bokmål";
+    NorthNdebele => "nd", "North Ndebele", "isiNdebele";
+    Nepali => "ne", "Nepali", "नेपाली";
+    Ndonga => "ng", "Ndonga", "Owambo";
+    Dutch => "nl", "Dutch", "Nederlands";
+    NorwegianNynorsk => "nn", "Norwegian Nynorsk", "Norsk nynorsk";
+    Norwegian => "no", "Norwegian", "Norsk";
+    SouthNdebele => "nr", "South Ndebele", "isiNdebele";
+    Navajo => "nv", "Navajo", "Diné bizaad";
+    Chichewa => "ny", "Chichewa", "chiCheŵa";
+    Occitan => "oc", "Occitan", "occitan";
+    Ojibwa => "oj", "Ojibwa", "ᐊᓂᔑᓈᐯᒧᐎᓐ";
+    Oromo => "om", "Oromo", "Afaan Oromoo";
+    Oriya => "or", "Oriya", "ଓଡ଼ିଆ";
+    Ossetian => "os", "Ossetian", "ирон æвзаг";
+    Punjabi => "pa", "Punjabi", "ਪੰਜਾਬੀ";
+    Pali => "pi", "Pali", "पाऴि";
+    Polish => "pl", "Polish", "polski";
+    Pashto => "ps", "Pashto", "پښتو";
+    Portuguese => "pt", "Portuguese", "português";
+    Quechua => "qu", "Quechua", "Runa Simi";
+    Romansh => "rm", "Romansh", "rumantsch grischun";
+    Rundi => "rn", "Rundi", "Ikirundi";
+    Romanian => "ro", "Romanian", "română";
+    Russian => "ru", "Russian", "русский";
+    Kinyarwanda => "rw", "Kinyarwanda", "Ikinyarwanda";
+    Sanskrit => "sa", "Sanskrit", "संस्कृतम्";
+    Sardinian => "sc", "Sardinian", "sardu";
+    Sindhi => "sd", "Sindhi", "सिन्धी";
+    NorthernSami => "se", "Northern Sami", "Davvisámegiella";
+    Sango => "sg", "Sango", "yângâ tî sängö";
+    Sinhala => "si", "Sinhala", "සිංහල";
+    Slovak => "sk", "Slovak", "slovenčina";
+    Slovenian => "sl", "Slovenian", "slovenščina";
+    Samoan => "sm", "Samoan", "gagana fa'a Samoa";
+    Shona => "sn", "Shona", "chiShona";
+    Somali => "so", "Somali", "Soomaaliga";
+    Albanian => "sq", "Albanian", "Shqip";
+    Serbian => "sr", "Serbian", "српски језик";
+    Swati => "ss", "Swati", "SiSwati";
+    SouthernSotho => "st", "Southern Sotho", "Sesotho";
+    Sundanese => "su", "Sundanese", "Basa Sunda";
+    Swedish => "sv", "Swedish", "svenska";
+    Swahili => "sw", "Swahili", "Kiswahili";
+    Tamil => "ta", "Tamil", "தமிழ்";
+    Telugu => "te", "Telugu", "తెలుగు";
+    Tajik => "tg", "Tajik", "тоҷикӣ";
+    Thai => "th", "Thai", "ไทย";
+    Tigrinya => "ti", "Tigrinya", "ትግርኛ";
+    Turkmen => "tk", "Turkmen", "Türkmençe";
+    Tagalog => "tl", "Tagalog", "Wikang Tagalog";
+    Tswana => "tn", "Tswana", "Setswana";
+    Tonga => "to", "Tonga", "faka Tonga";
+    Turkish => "tr", "Turkish", "Türkçe";
+    Tsonga => "ts", "Tsonga", "Xitsonga";
+    Tatar => "tt", "Tatar", "татар теле";
+    Twi => "tw", "Twi", "Twi";
+    Tahitian => "ty", "Tahitian", "Reo Tahiti";
+    Uighur => "ug", "Uighur", "ئۇيغۇرچە";
+    Ukrainian => "uk", "Ukrainian", "українська";
+    Urdu => "ur", "Urdu", "اردو";
+    Uzbek => "uz", "Uzbek", "Oʻzbek";
+    Venda => "ve", "Venda", "Tshivenḓa";
+    Vietnamese => "vi", "Vietnamese", "Tiếng Việt";
+    Volapuk => "vo", "Volapük", "Volapük";
+    Walloon => "wa", "Walloon", "walon";
+    Wolof => "wo", "Wolof", "Wollof";
+    Xhosa => "xh", "Xhosa", "isiXhosa";
+    Yiddish => "yi", "Yiddish", "ייִדיש";
+    Yoruba => "yo", "Yoruba", "Yorùbá";
+    Zhuang => "za", "Zhuang", "Saɯ cueŋƅ";
+    Chinese => "zh", "Chinese", "中文";
+    Zulu => "zu", "Zulu", "isiZulu";
+}
+
+impl Language {
+    /// Whether the language is one of the ISO 639-1 languages known to this crate.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Language::Unknown(_))
+    }
+}
+
+impl FmtDisplay for Language {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.code())
+    }
+}
+
+impl From<String> for Language {
+    fn from(value: String) -> Self {
+        match value.parse() {
+            Ok(language) => language,
+            Err(infallible) => match infallible {},
+        }
+    }
+}
+
+impl From<Language> for String {
+    fn from(language: Language) -> Self {
+        match language {
+            Language::Unknown(code) => code,
+            language => language.code().to_owned(),
+        }
+    }
+}
+
+impl From<&Language> for Value {
+    fn from(language: &Language) -> Self {
+        Value::from(language.code())
+    }
+}
+
 impl From<Chart> for Value {
     fn from(format: Chart) -> Self {
         Value::from(format.to_string())
@@ -52,4 +888,69 @@ impl From<SortBy> for Value {
     fn from(format: SortBy) -> Self {
         Value::from(format.to_string())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use api_request_utils::serde_json::{self, json};
+
+    use super::*;
+    use crate::Artist;
+
+    fn artist(country : &str) -> Artist {
+        serde_json::from_value(json!({
+            "artist_id" : 118, "artist_name" : "Daft Punk", "artist_name_translation_list" : [], "artist_comment" : "", "artist_country" : country,
+            "artist_alias_list" : [], "artist_rating" : 80, "restricted" : false, "begin_date_year" : 1993, "begin_date" : "1993-00-00", "end_date_year" : 2021, "end_date" : "2021-02-22",
+        })).unwrap()
+    }
+
+    #[test]
+    fn parses_countries() {
+        assert_eq!("it".parse::<Country>(), Ok(Country::Italy));
+        assert_eq!("It".parse::<Country>(), Ok(Country::Italy));
+        assert_eq!(Country::try_from(String::from(" gb ")), Ok(Country::UnitedKingdom));
+        assert!("ZZ".parse::<Country>().is_err());
+        assert!("ITA".parse::<Country>().is_err());
+
+        assert_eq!(Country::Worldwide.code(), "XW");
+        assert_eq!(Country::Worldwide.name(), "Worldwide");
+        assert_eq!("xw".parse::<Country>(), Ok(Country::Worldwide));
+        assert_eq!(Country::CoteDIvoire.name(), "Côte d'Ivoire");
+        assert_eq!(Country::UnitedStates.to_string(), "US");
+        assert_eq!(serde_json::to_value(Country::Japan).unwrap(), json!("JP"));
+        assert_eq!(Value::from(Country::Japan), json!("JP"));
+    }
+
+    #[test]
+    fn unknown_artist_countries_are_none() {
+        assert_eq!(artist("fr").country(), &Some(Country::France));
+        assert_eq!(artist("").country(), &None);
+        assert_eq!(artist("YU").country(), &None);
+    }
+
+    #[test]
+    fn parses_languages() {
+        assert_eq!("it".parse::<Language>(), Ok(Language::Italian));
+        assert_eq!(Language::from(String::from(" JA ")), Language::Japanese);
+        assert!(Language::Italian.is_known());
+        assert_eq!(Language::Italian.name(), Some("Italian"));
+        assert_eq!(Language::Italian.native_name(), Some("italiano"));
+        assert_eq!(Language::Japanese.native_name(), Some("日本語"));
+        assert_eq!(Language::English.to_string(), "en");
+    }
+
+    #[test]
+    fn keeps_unknown_languages() {
+        let unknown = Language::from(String::from("XX"));
+        assert_eq!(unknown, Language::Unknown(String::from("xx")));
+        assert!(!unknown.is_known());
+        assert_eq!((unknown.code(), unknown.name(), unknown.native_name()), ("xx", None, None));
+        assert_eq!(unknown.to_string(), "xx");
+        assert_eq!(Value::from(&unknown), json!("xx"));
+
+        for language in [Language::French, unknown] {
+            let value = serde_json::to_value(&language).unwrap();
+            assert_eq!(serde_json::from_value::<Language>(value).unwrap(), language);
+        }
+        assert_eq!(serde_json::from_value::<Language>(json!("zz-latn")).unwrap(), Language::Unknown(String::from("zz-latn")));
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Error returned when a query is built with a parameter outside of its documented range.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ValidationError {
+    /// A numeric parameter lies outside of the range accepted by the API.
+    OutOfRange {
+        /// The query key of the parameter.
+        parameter : &'static str,
+        /// The rejected value.
+        value : f64,
+        /// The smallest accepted value.
+        min : f64,
+        /// The largest accepted value.
+        max : f64,
+    },
+    /// None of a set of alternative parameters, one of which the endpoint requires, is set.
+    MissingParameter {
+        /// The query keys of the alternatives.
+        parameters : &'static [&'static str],
+    },
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            ValidationError::OutOfRange { parameter, value, min, max } => write!(f,"`{parameter}` must be between {min} and {max}, got {value}"),
+            ValidationError::MissingParameter { parameters } => write!(f,"one of `{}` must be set",parameters.join("`, `")),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Error returned when parsing an identifier fails.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum IdError {
+    /// The value is not a valid numeric Musixmatch id.
+    InvalidNumber(String),
+    /// The value is not a valid ISRC, which consists of a two letter country code, a three character
+    /// registrant code, two digits for the year and a five digit designation code.
+    InvalidIsrc(String),
+    /// The value is not a valid MusicBrainz identifier (UUID).
+    InvalidMbid(String),
+}
+
+impl Display for IdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            IdError::InvalidNumber(value) => write!(f,"`{value}` is not a valid Musixmatch id"),
+            IdError::InvalidIsrc(value) => write!(f,"`{value}` is not a valid ISRC"),
+            IdError::InvalidMbid(value) => write!(f,"`{value}` is not a valid MusicBrainz id"),
+        }
+    }
+}
+
+impl std::error::Error for IdError {}
+
+/// Error returned when parsing or converting a date fails.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DateError {
+    /// The value is not a date in one of the `YYYY`, `YYYY-MM`, `YYYY-MM-DD` or `YYYYMMDD` forms, lies outside
+    /// of the years 1 to 9999, or lacks the month or day a conversion needs.
+    InvalidDate(String),
+    /// The value is not an RFC 3339 timestamp such as `2016-04-14T13:48:22Z`, or lies outside of the years 1 to 9999.
+    InvalidTimestamp(String),
+}
+
+impl Display for DateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            DateError::InvalidDate(value) => write!(f,"`{value}` is not a valid date"),
+            DateError::InvalidTimestamp(value) => write!(f,"`{value}` is not a valid timestamp"),
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+/// Error returned when parsing a track search string fails, pointing at the offending token.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SearchSyntaxError {
+    /// The byte offset of the token within the search string.
+    pub position : usize,
+    /// The token as written in the search string.
+    pub token : String,
+    /// What is wrong with the token.
+    pub kind : SearchSyntaxErrorKind,
+}
+
+/// The reasons a token of a track search string can be rejected for.
+#[derive(Clone, PartialEq, Debug)]
+pub enum SearchSyntaxErrorKind {
+    /// A quoted value lacks its closing quote.
+    UnterminatedQuote,
+    /// The key is not one of the supported search keys.
+    UnknownKey,
+    /// The key was already given earlier in the search string.
+    RepeatedKey,
+    /// The value could not be parsed; holds a description of what was expected.
+    InvalidValue(&'static str),
+    /// The value was parsed but is not accepted by the API.
+    Invalid(ValidationError),
+}
+
+impl Display for SearchSyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let SearchSyntaxError { position, token, kind } = self;
+        match kind {
+            SearchSyntaxErrorKind::UnterminatedQuote => write!(f,"unterminated quote in `{token}` at position {position}"),
+            SearchSyntaxErrorKind::UnknownKey => write!(f,"unknown search key in `{token}` at position {position}"),
+            SearchSyntaxErrorKind::RepeatedKey => write!(f,"repeated search key in `{token}` at position {position}"),
+            SearchSyntaxErrorKind::InvalidValue(expected) => write!(f,"invalid value in `{token}` at position {position}, expected {expected}"),
+            SearchSyntaxErrorKind::Invalid(error) => write!(f,"invalid value in `{token}` at position {position}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SearchSyntaxError {}
+
+/// Error returned when reading synchronised lyrics fails.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SyncedLyricsError {
+    /// A line is neither a metadata tag nor a timed line of lyrics.
+    MalformedLine {
+        /// The line number, starting at 1.
+        line : usize,
+        /// The content of the line.
+        content : String,
+    },
+    /// The DFXP document is not well-formed XML or not a `tt` document; holds a description of the problem.
+    InvalidDfxp(String),
+    /// A DFXP time expression such as `begin="00:00:12.5"` could not be parsed.
+    InvalidTime(String),
+    /// The body is not a JSON subtitle in the `mxm` format; holds the error reported by the JSON parser.
+    InvalidMxm(String),
+    /// The body is not richsync JSON; holds the error reported by the JSON parser.
+    InvalidRichsync(String),
+}
+
+impl Display for SyncedLyricsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            SyncedLyricsError::MalformedLine { line, content } => write!(f,"malformed line {line}: `{content}`"),
+            SyncedLyricsError::InvalidDfxp(reason) => write!(f,"invalid DFXP document: {reason}"),
+            SyncedLyricsError::InvalidTime(value) => write!(f,"`{value}` is not a valid time expression"),
+            SyncedLyricsError::InvalidMxm(reason) => write!(f,"invalid mxm subtitle: {reason}"),
+            SyncedLyricsError::InvalidRichsync(reason) => write!(f,"invalid richsync body: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SyncedLyricsError {}
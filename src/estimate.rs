@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use crate::{CleanLyrics, Lyrics, SyncedLine, SyncedLyrics};
+
+/// What the time of a song is shared out by when estimating line timings.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub enum TimingWeight {
+    /// Lines last in proportion to their syllables, estimated from vowel groups, or characters in scripts without spaces.
+    #[default]
+    Syllables,
+    /// Lines last in proportion to their characters, not counting whitespace.
+    Characters,
+}
+
+/// Options for [SyncedLyrics::estimate].
+#[derive(Clone, Debug, PartialEq)]
+pub struct EstimateOptions {
+    weight : TimingWeight,
+    intro : Duration,
+    outro : Duration,
+    pause : f64,
+}
+
+impl Default for EstimateOptions {
+    fn default() -> Self {
+        EstimateOptions {
+            weight : TimingWeight::Syllables,
+            intro : Duration::from_secs(10),
+            outro : Duration::from_secs(10),
+            pause : 1.0,
+        }
+    }
+}
+
+impl EstimateOptions {
+    /// Creates options weighting lines by syllables, with 10 seconds before the first and after the last line and pauses as long as an average line.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set what lines are weighted by.
+    pub fn weight(mut self, weight : TimingWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Set the time before the first line and after the last one. Both are shortened to leave at least half the song for the lines.
+    pub fn padding(mut self, intro : Duration, outro : Duration) -> Self {
+        self.intro = intro;
+        self.outro = outro;
+        self
+    }
+
+    /// Set how long a pause, one or more blank lines between verses, lasts compared to an average line.
+    pub fn pause(mut self, pause : f64) -> Self {
+        self.pause = if pause.is_finite() { pause.max(0.0) } else { 0.0 };
+        self
+    }
+}
+
+/// Estimates the syllables of `line`: the vowel groups of every word, or every letter of words written without vowels such as Chinese or Japanese.
+fn syllables(line : &str) -> usize {
+    line.split_whitespace()
+        .map(|word| {
+            let word = word.to_lowercase();
+            if !word.chars().any(|c| c.is_ascii_alphabetic()) {
+                return word.chars().filter(|c| c.is_alphabetic()).count();
+            }
+
+            let is_vowel = |c : char| "aeiouyàáâäèéêëìíîïòóôöùúûü".contains(c);
+            let letters : Vec<char> = word.chars().filter(|c| c.is_alphabetic()).collect();
+            let groups = letters.iter().enumerate().filter(|(index,c)| is_vowel(**c) && (*index == 0 || !is_vowel(letters[index - 1]))).count();
+            // A final silent `e`, as in "make", is not a syllable of its own.
+            let silent = letters.len() > 2 && letters.ends_with(&['e']) && !is_vowel(letters[letters.len() - 2]) && !letters.ends_with(&['l','e']);
+            groups.saturating_sub(silent as usize).max(1)
+        })
+        .sum()
+}
+
+/// A lyric line or a pause between verses.
+enum Part<'s> {
+    Line(&'s str,f64),
+    Pause,
+}
+
+impl SyncedLyrics {
+    /// Spreads the lines of plain lyrics over a song of the given `duration`, for scrolling lyrics where no subtitle exists.
+    ///
+    /// The lines share the time left after the intro and outro in proportion to their weight, and each blank line
+    /// between verses is a pause, marked by an empty line. Lines get end times and the `length` tag is set to `duration`.
+    /// The lyrics are cleaned with [CleanLyrics::new] first.
+    pub fn estimate(lyrics : &str,duration : Duration,options : &EstimateOptions) -> Self {
+        let lyrics = CleanLyrics::new(lyrics);
+        let mut parts = Vec::new();
+        for line in lyrics.lines() {
+            match line.is_empty() {
+                true => parts.push(Part::Pause),
+                false => {
+                    let weight = match options.weight {
+                        TimingWeight::Syllables => syllables(line),
+                        TimingWeight::Characters => line.chars().filter(|c| !c.is_whitespace()).count(),
+                    };
+                    parts.push(Part::Line(line,weight.max(1) as f64));
+                },
+            }
+        }
+
+        let mut estimated = SyncedLyrics::default();
+        estimated.set_tag("length",format!("{:02}:{:02}",duration.as_secs() / 60,duration.as_secs() % 60));
+
+        let lines : Vec<f64> = parts.iter().filter_map(|part| match part { Part::Line(_,weight) => Some(*weight), Part::Pause => None }).collect();
+        if lines.is_empty() {
+            return estimated;
+        }
+        let pause = options.pause * lines.iter().sum::<f64>() / lines.len() as f64;
+        let total : f64 = parts.iter().map(|part| match part { Part::Line(_,weight) => *weight, Part::Pause => pause }).sum();
+
+        let padding = options.intro + options.outro;
+        let scale = match padding > duration / 2 {
+            true => (duration / 2).as_secs_f64() / padding.as_secs_f64(),
+            false => 1.0,
+        };
+        let mut time = options.intro.as_secs_f64() * scale;
+        let per_weight = (duration.as_secs_f64() - padding.as_secs_f64() * scale) / total;
+
+        let millis = |seconds : f64| Duration::from_millis((seconds * 1000.0).round() as u64);
+        for part in parts {
+            let (line,weight) = match part {
+                Part::Line(line,weight) => (line,weight),
+                Part::Pause => ("",pause),
+            };
+            let end = time + weight * per_weight;
+            match line.is_empty() {
+                true => estimated.lines.push(SyncedLine::new(millis(time),"")),
+                false => estimated.lines.push(SyncedLine::new(millis(time),line).with_end(millis(end))),
+            }
+            time = end;
+        }
+        estimated
+    }
+}
+
+impl Lyrics {
+    /// Estimates line timings for the lyrics of a track lasting `duration`, see [SyncedLyrics::estimate].
+    pub fn estimate_timings(&self,duration : Duration,options : &EstimateOptions) -> SyncedLyrics {
+        SyncedLyrics::estimate(self.lyrics(),duration,options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_syllables() {
+        assert_eq!(syllables("Make it better, do it faster"), 8);
+        assert_eq!(syllables("little table"), 4);
+        assert_eq!(syllables("我爱你"), 3);
+    }
+
+    #[test]
+    fn spreads_lines_over_the_song() {
+        let lyrics = "\nWork it\nMake it\n\n\nDo it, makes us\n\n******* This Lyrics is NOT for Commercial use *******\n(1409617737497)";
+        let estimated = SyncedLyrics::estimate(lyrics, Duration::from_secs(30), &EstimateOptions::new().padding(Duration::from_secs(5), Duration::from_secs(5)));
+
+        assert_eq!(estimated.length(), Some(Duration::from_secs(30)));
+        assert_eq!(estimated.lines(), &[
+            SyncedLine::new(Duration::from_secs(5), "Work it").with_end(Duration::from_millis(8_333)),
+            SyncedLine::new(Duration::from_millis(8_333), "Make it").with_end(Duration::from_millis(11_667)),
+            SyncedLine::new(Duration::from_millis(11_667), ""),
+            SyncedLine::new(Duration::from_millis(16_667), "Do it, makes us").with_end(Duration::from_secs(25)),
+        ]);
+
+        let characters = SyncedLyrics::estimate(lyrics, Duration::from_secs(30), &EstimateOptions::new().weight(TimingWeight::Characters).padding(Duration::from_secs(20), Duration::ZERO).pause(0.0));
+        assert_eq!(characters.lines().iter().map(|line| line.time().as_millis()).collect::<Vec<_>>(), [15_000, 18_750, 22_500, 22_500]);
+    }
+}
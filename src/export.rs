@@ -0,0 +1,412 @@
+use std::{fmt::Write, time::Duration};
+
+use strum::Display;
+
+use crate::SyncedLyrics;
+
+/// Options shared by the subtitle exporters of [SyncedLyrics].
+///
+/// A line ends at its own end time if the source format carries one (DFXP), otherwise when the next line
+/// starts, and the last line at the end of the track. Empty lines are not exported, they only end the line
+/// before them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportOptions {
+    track_length : Option<Duration>,
+    last_line_duration : Duration,
+    max_line_length : Option<usize>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            track_length : None,
+            last_line_duration : Duration::from_secs(5),
+            max_line_length : None,
+        }
+    }
+}
+
+impl ExportOptions {
+    /// Creates options ending the last line 5 seconds after it starts, unless the track length is known, and without wrapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the length of the track, which the last line ends at. Defaults to the `length` tag of the lyrics.
+    pub fn track_length(mut self, track_length : Duration) -> Self {
+        self.track_length = Some(track_length);
+        self
+    }
+
+    /// Set how long the last line is shown when the track length is unknown.
+    pub fn last_line_duration(mut self, last_line_duration : Duration) -> Self {
+        self.last_line_duration = last_line_duration;
+        self
+    }
+
+    /// Wrap rows longer than this many characters at word boundaries.
+    pub fn max_line_length(mut self, max_line_length : usize) -> Self {
+        self.max_line_length = Some(max_line_length.max(1));
+        self
+    }
+}
+
+/// The alignment of a WebVTT cue.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[derive(Display)]
+#[allow(missing_docs)]
+pub enum VttAlign {
+    #[strum(serialize = "start")]
+    Start,
+    #[strum(serialize = "center")]
+    Center,
+    #[strum(serialize = "end")]
+    End,
+    #[strum(serialize = "left")]
+    Left,
+    #[strum(serialize = "right")]
+    Right,
+}
+
+/// The cue settings written after the timing of every WebVTT cue, e.g. `line:90% align:center`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VttCueSettings {
+    line : Option<f32>,
+    position : Option<f32>,
+    size : Option<f32>,
+    align : Option<VttAlign>,
+}
+
+impl VttCueSettings {
+    /// Creates settings leaving every cue at the player's defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the vertical position of the cues, as a percentage of the video height.
+    pub fn line(mut self, percent : f32) -> Self {
+        self.line = Some(percent.clamp(0.0, 100.0));
+        self
+    }
+
+    /// Set the horizontal position of the cues, as a percentage of the video width.
+    pub fn position(mut self, percent : f32) -> Self {
+        self.position = Some(percent.clamp(0.0, 100.0));
+        self
+    }
+
+    /// Set the width of the cue box, as a percentage of the video width.
+    pub fn size(mut self, percent : f32) -> Self {
+        self.size = Some(percent.clamp(0.0, 100.0));
+        self
+    }
+
+    /// Set the alignment of the text within the cue box.
+    pub fn align(mut self, align : VttAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    fn render(&self) -> String {
+        let mut settings = Vec::new();
+        if let Some(line) = self.line {
+            settings.push(format!("line:{line}%"));
+        }
+        if let Some(position) = self.position {
+            settings.push(format!("position:{position}%"));
+        }
+        if let Some(size) = self.size {
+            settings.push(format!("size:{size}%"));
+        }
+        if let Some(align) = self.align {
+            settings.push(format!("align:{align}"));
+        }
+        settings.join(" ")
+    }
+}
+
+/// The style every line of an ASS/SSA script is rendered with.
+///
+/// Colours are given as `(red, green, blue)`. The secondary colour is the one karaoke effects fill from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssStyle {
+    font_name : String,
+    font_size : u32,
+    primary_colour : (u8,u8,u8),
+    secondary_colour : (u8,u8,u8),
+    outline_colour : (u8,u8,u8),
+    bold : bool,
+    italic : bool,
+    outline : f32,
+    shadow : f32,
+    alignment : u8,
+    margin_vertical : u32,
+    resolution : (u32,u32),
+}
+
+impl Default for AssStyle {
+    fn default() -> Self {
+        AssStyle {
+            font_name : "Arial".to_owned(),
+            font_size : 48,
+            primary_colour : (255,255,255),
+            secondary_colour : (255,0,0),
+            outline_colour : (0,0,0),
+            bold : false,
+            italic : false,
+            outline : 2.0,
+            shadow : 0.0,
+            alignment : 2,
+            margin_vertical : 40,
+            resolution : (1920,1080),
+        }
+    }
+}
+
+impl AssStyle {
+    /// Creates the default style: white 48px Arial with a black outline, centred at the bottom of a 1920x1080 frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the font and its size in pixels of the script resolution.
+    pub fn font(mut self, font_name : impl Into<String>, font_size : u32) -> Self {
+        self.font_name = font_name.into();
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set the colour of the text.
+    pub fn primary_colour(mut self, red : u8, green : u8, blue : u8) -> Self {
+        self.primary_colour = (red,green,blue);
+        self
+    }
+
+    /// Set the colour karaoke effects fill the text from.
+    pub fn secondary_colour(mut self, red : u8, green : u8, blue : u8) -> Self {
+        self.secondary_colour = (red,green,blue);
+        self
+    }
+
+    /// Set the colour of the outline.
+    pub fn outline_colour(mut self, red : u8, green : u8, blue : u8) -> Self {
+        self.outline_colour = (red,green,blue);
+        self
+    }
+
+    /// Set whether the text is bold and italic.
+    pub fn emphasis(mut self, bold : bool, italic : bool) -> Self {
+        self.bold = bold;
+        self.italic = italic;
+        self
+    }
+
+    /// Set the width of the outline and the depth of the shadow, in pixels.
+    pub fn border(mut self, outline : f32, shadow : f32) -> Self {
+        self.outline = outline.max(0.0);
+        self.shadow = shadow.max(0.0);
+        self
+    }
+
+    /// Set the alignment as on a numeric keypad: 1 to 3 at the bottom, 4 to 6 in the middle and 7 to 9 at the top.
+    pub fn alignment(mut self, alignment : u8) -> Self {
+        self.alignment = alignment.clamp(1, 9);
+        self
+    }
+
+    /// Set the distance of the text from the top or bottom edge, in pixels.
+    pub fn margin_vertical(mut self, margin_vertical : u32) -> Self {
+        self.margin_vertical = margin_vertical;
+        self
+    }
+
+    /// Set the resolution the sizes and margins refer to.
+    pub fn resolution(mut self, width : u32, height : u32) -> Self {
+        self.resolution = (width.max(1),height.max(1));
+        self
+    }
+}
+
+/// A line ready to be exported, with its end time resolved and its text wrapped.
+pub(crate) struct Cue {
+    pub(crate) start : Duration,
+    pub(crate) end : Duration,
+    pub(crate) rows : Vec<String>,
+}
+
+/// Wraps `row` at word boundaries into rows of at most `width` characters. Longer words are kept whole.
+fn wrap(row : &str,width : usize) -> Vec<String> {
+    let mut rows : Vec<String> = Vec::new();
+    for word in row.split_whitespace() {
+        match rows.last_mut() {
+            Some(last) if last.chars().count() + 1 + word.chars().count() <= width => {
+                last.push(' ');
+                last.push_str(word);
+            },
+            _ => rows.push(word.to_owned()),
+        }
+    }
+    rows
+}
+
+impl SyncedLyrics {
+    /// The time the line at `index` ends at, as described on [ExportOptions].
+    pub(crate) fn line_end(&self,index : usize,options : &ExportOptions) -> Duration {
+        let line = &self.lines[index];
+        let start = *line.time();
+        let next = self.lines[index + 1..].iter().map(|next| *next.time()).find(|next| *next > start);
+        let end = line.end()
+            .or(next)
+            .or(options.track_length.or_else(|| self.length()).filter(|length| *length > start))
+            .unwrap_or(start.saturating_add(options.last_line_duration));
+        end.max(start)
+    }
+
+    /// The non-empty lines with their end times resolved as described on [ExportOptions].
+    pub(crate) fn cues(&self,options : &ExportOptions) -> Vec<Cue> {
+        self.lines.iter().enumerate()
+            .filter(|(_,line)| !line.text().trim().is_empty())
+            .map(|(index,line)| {
+                let rows = line.text().lines()
+                    .flat_map(|row| match options.max_line_length {
+                        Some(width) => wrap(row,width),
+                        None => vec![row.trim().to_owned()],
+                    })
+                    .filter(|row| !row.is_empty())
+                    .collect();
+
+                Cue { start : *line.time(), end : self.line_end(index,options), rows }
+            })
+            .collect()
+    }
+
+    /// Writes the lyrics as SubRip (`.srt`) subtitles.
+    pub fn to_srt(&self,options : &ExportOptions) -> String {
+        let mut srt = String::new();
+        for (index,cue) in self.cues(options).iter().enumerate() {
+            let time = |time : Duration| format_time(time,',');
+            let _ = write!(srt,"{}\n{} --> {}\n{}\n\n",index + 1,time(cue.start),time(cue.end),cue.rows.join("\n"));
+        }
+        srt
+    }
+
+    /// Writes the lyrics as WebVTT (`.vtt`) subtitles, adding `cue_settings` to every cue.
+    pub fn to_vtt(&self,options : &ExportOptions,cue_settings : &VttCueSettings) -> String {
+        let settings = match cue_settings.render() {
+            settings if settings.is_empty() => settings,
+            settings => format!(" {settings}"),
+        };
+
+        let mut vtt = String::from("WEBVTT\n\n");
+        for cue in self.cues(options) {
+            let time = |time : Duration| format_time(time,'.');
+            let text = cue.rows.join("\n").replace('&',"&amp;").replace('<',"&lt;").replace('>',"&gt;");
+            let _ = write!(vtt,"{} --> {}{settings}\n{text}\n\n",time(cue.start),time(cue.end));
+        }
+        vtt
+    }
+
+    /// Writes the lyrics as an Advanced SubStation Alpha (`.ass`) script using `style`.
+    ///
+    /// The `ti` tag becomes the title of the script. Text is written as is, so override blocks such as `{\i1}` take effect.
+    pub fn to_ass(&self,options : &ExportOptions,style : &AssStyle) -> String {
+        let mut ass = ass_header(self.title().unwrap_or_default(),style);
+        for cue in self.cues(options) {
+            let _ = writeln!(ass,"Dialogue: 0,{},{},Default,,0,0,0,,{}",format_ass_time(cue.start),format_ass_time(cue.end),cue.rows.join("\\N"));
+        }
+        ass
+    }
+}
+
+/// Formats a time as `hh:mm:ss` followed by `separator` and the milliseconds, as used by SRT and WebVTT.
+fn format_time(time : Duration,separator : char) -> String {
+    let millis = time.as_millis();
+    format!("{:02}:{:02}:{:02}{separator}{:03}",millis / 3_600_000,millis / 60_000 % 60,millis / 1000 % 60,millis % 1000)
+}
+
+/// Formats a time as `h:mm:ss.cc`, as used by ASS.
+pub(crate) fn format_ass_time(time : Duration) -> String {
+    let centis = time.as_millis() / 10;
+    format!("{}:{:02}:{:02}.{:02}",centis / 360_000,centis / 6000 % 60,centis / 100 % 60,centis % 100)
+}
+
+/// Formats a colour as `&HAABBGGRR` with full opacity.
+fn ass_colour((red,green,blue) : (u8,u8,u8)) -> String {
+    format!("&H00{blue:02X}{green:02X}{red:02X}")
+}
+
+/// The script info, style and event format sections of an ASS script.
+pub(crate) fn ass_header(title : &str,style : &AssStyle) -> String {
+    let flag = |set : bool| if set { -1 } else { 0 };
+    format!("[Script Info]
+Title: {title}
+ScriptType: v4.00+
+WrapStyle: 0
+PlayResX: {}
+PlayResY: {}
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,{},{},{},{},{},&H80000000,{},{},0,0,100,100,0,0,1,{},{},{},20,20,{},1
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+",
+        style.resolution.0,style.resolution.1,
+        style.font_name,style.font_size,ass_colour(style.primary_colour),ass_colour(style.secondary_colour),ass_colour(style.outline_colour),
+        flag(style.bold),flag(style.italic),style.outline,style.shadow,style.alignment,style.margin_vertical,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LRC : &str = "[ti:Around the World]
+[length:00:20]
+[00:01.00]Around the world, around the world
+[00:03.50]
+[00:04.00]<Around> & around
+[00:12.25]Around the world
+";
+
+    #[test]
+    fn exports_srt_with_resolved_end_times() {
+        let lyrics = SyncedLyrics::from_lrc(LRC);
+
+        assert_eq!(lyrics.to_srt(&ExportOptions::new().max_line_length(20)), "1
+00:00:01,000 --> 00:00:03,500
+Around the world,
+around the world
+
+2
+00:00:04,000 --> 00:00:12,250
+<Around> & around
+
+3
+00:00:12,250 --> 00:00:20,000
+Around the world
+
+");
+        assert!(lyrics.to_srt(&ExportOptions::new().track_length(Duration::from_secs(10))).ends_with("00:00:12,250 --> 00:00:17,250\nAround the world\n\n"));
+        assert_eq!(SyncedLyrics::from_lrc("[00:12.00]Work it").line_end(0, &ExportOptions::new().last_line_duration(Duration::MAX)), Duration::MAX);
+    }
+
+    #[test]
+    fn exports_vtt_with_cue_settings() {
+        let vtt = SyncedLyrics::from_lrc(LRC).to_vtt(&ExportOptions::new(), &VttCueSettings::new().line(90.0).align(VttAlign::Center));
+
+        assert!(vtt.starts_with("WEBVTT\n\n00:00:01.000 --> 00:00:03.500 line:90% align:center\nAround the world, around the world\n\n"));
+        assert!(vtt.contains("\n&lt;Around&gt; &amp; around\n"));
+    }
+
+    #[test]
+    fn exports_ass_with_style() {
+        let ass = SyncedLyrics::from_lrc(LRC).to_ass(&ExportOptions::new().max_line_length(20), &AssStyle::new().font("Helvetica", 64).primary_colour(255, 200, 0));
+
+        assert!(ass.starts_with("[Script Info]\nTitle: Around the World\n"));
+        assert!(ass.contains("\nStyle: Default,Helvetica,64,&H0000C8FF,&H000000FF,&H00000000,&H80000000,0,0,0,0,100,100,0,0,1,2,0,2,20,20,40,1\n"));
+        assert!(ass.contains("\nDialogue: 0,0:00:01.00,0:00:03.50,Default,,0,0,0,,Around the world,\\Naround the world\n"));
+        assert!(ass.ends_with("\nDialogue: 0,0:00:12.25,0:00:20.00,Default,,0,0,0,,Around the world\n"));
+    }
+}
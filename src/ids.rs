@@ -0,0 +1,247 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use api_request_utils::{
+    serde_json::Value,
+    serde::{Deserialize, Serialize},
+};
+
+use crate::IdError;
+
+macro_rules! numeric_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        #[derive(Deserialize, Serialize)]
+        #[serde(crate = "api_request_utils::serde", transparent)] // must be below the derive attribute
+        pub struct $name(pub u32);
+
+        impl $name {
+            /// Returns the raw numeric value.
+            pub fn get(self) -> u32 {
+                self.0
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(id : u32) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(id : $name) -> Self {
+                id.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = IdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.trim().parse().map($name).map_err(|_| IdError::InvalidNumber(s.to_owned()))
+            }
+        }
+
+        impl From<$name> for Value {
+            fn from(id: $name) -> Self {
+                Value::from(id.0)
+            }
+        }
+    };
+}
+
+numeric_id! {
+    /// The Musixmatch id of a single recording of a track (`track_id`).
+    TrackId
+}
+
+numeric_id! {
+    /// The Musixmatch id shared by every recording of the same song (`commontrack_id`).
+    CommonTrackId
+}
+
+numeric_id! {
+    /// The Musixmatch id of an artist (`artist_id`).
+    ArtistId
+}
+
+numeric_id! {
+    /// The Musixmatch id of an album (`album_id`).
+    AlbumId
+}
+
+/// An International Standard Recording Code, e.g. `USVI20400001`.
+///
+/// Parsing accepts the hyphenated form (`US-VI2-04-00001`) and lowercase letters,
+/// and stores the code as twelve uppercase characters.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(Deserialize, Serialize)]
+#[serde(crate = "api_request_utils::serde", try_from = "String", into = "String")] // must be below the derive attribute
+pub struct Isrc(String);
+
+impl Isrc {
+    /// Returns the code as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The two letter country code of the registrant.
+    pub fn country_code(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// The three character code of the registrant.
+    pub fn registrant_code(&self) -> &str {
+        &self.0[2..5]
+    }
+
+    /// The last two digits of the year of reference.
+    pub fn year(&self) -> &str {
+        &self.0[5..7]
+    }
+
+    /// The five digit designation code.
+    pub fn designation_code(&self) -> &str {
+        &self.0[7..]
+    }
+}
+
+impl FromStr for Isrc {
+    type Err = IdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code : String = s.trim().chars().filter(|c| *c != '-').map(|c| c.to_ascii_uppercase()).collect();
+        let bytes = code.as_bytes();
+
+        let valid = bytes.len() == 12
+            && bytes[..2].iter().all(u8::is_ascii_uppercase)
+            && bytes[2..5].iter().all(u8::is_ascii_alphanumeric)
+            && bytes[5..].iter().all(u8::is_ascii_digit);
+
+        match valid {
+            true => Ok(Isrc(code)),
+            false => Err(IdError::InvalidIsrc(s.to_owned())),
+        }
+    }
+}
+
+/// A MusicBrainz identifier, a UUID such as `056e4f3e-d505-4dad-8ec1-d04f521cbb56`.
+///
+/// Parsing accepts uppercase hex digits and stores the identifier in lowercase.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[derive(Deserialize, Serialize)]
+#[serde(crate = "api_request_utils::serde", try_from = "String", into = "String")] // must be below the derive attribute
+pub struct Mbid(String);
+
+impl Mbid {
+    /// Returns the identifier as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Mbid {
+    type Err = IdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = s.trim().to_ascii_lowercase();
+        let groups : Vec<&str> = id.split('-').collect();
+
+        let valid = groups.iter().map(|group| group.len()).eq([8, 4, 4, 4, 12])
+            && groups.iter().all(|group| group.bytes().all(|b| b.is_ascii_hexdigit()));
+
+        match valid {
+            true => Ok(Mbid(id)),
+            false => Err(IdError::InvalidMbid(s.to_owned())),
+        }
+    }
+}
+
+macro_rules! string_id {
+    ($name:ident) => {
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = IdError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl From<&$name> for Value {
+            fn from(id: &$name) -> Self {
+                Value::from(id.as_str())
+            }
+        }
+    };
+}
+
+string_id!(Isrc);
+string_id!(Mbid);
+
+#[cfg(test)]
+mod tests {
+    use api_request_utils::serde_json::{self, json};
+
+    use super::*;
+
+    #[test]
+    fn parses_numeric_ids() {
+        assert_eq!(" 118 ".parse::<ArtistId>(), Ok(ArtistId(118)));
+        assert_eq!("-1".parse::<TrackId>(), Err(IdError::InvalidNumber("-1".to_owned())));
+        assert_eq!(serde_json::from_value::<CommonTrackId>(json!(5)).unwrap(), CommonTrackId(5));
+        assert_eq!(Value::from(AlbumId(9)), json!(9));
+    }
+
+    #[test]
+    fn parses_isrcs() {
+        let isrc : Isrc = "us-vi2-04-00001".parse().unwrap();
+        assert_eq!(isrc.as_str(), "USVI20400001");
+        assert_eq!((isrc.country_code(), isrc.registrant_code(), isrc.year(), isrc.designation_code()), ("US", "VI2", "04", "00001"));
+        assert_eq!(" USVI20400001 ".parse::<Isrc>(), Ok(isrc));
+
+        let invalid = |code : &str| code.parse::<Isrc>() == Err(IdError::InvalidIsrc(code.to_owned()));
+        assert!(invalid("USVI2040000"));
+        assert!(invalid("USVI204000011"));
+        assert!(invalid("U1VI20400001"));
+        assert!(invalid("US-V_2-04-00001"));
+        assert!(invalid("USVI204A0001"));
+        assert!(invalid("USVÍ20400001"));
+        assert!(serde_json::from_value::<Isrc>(json!("not an isrc")).is_err());
+    }
+
+    #[test]
+    fn parses_mbids() {
+        let mbid : Mbid = "056E4F3E-D505-4DAD-8EC1-D04F521CBB56".parse().unwrap();
+        assert_eq!(mbid.as_str(), "056e4f3e-d505-4dad-8ec1-d04f521cbb56");
+        assert_eq!(serde_json::to_value(&mbid).unwrap(), json!("056e4f3e-d505-4dad-8ec1-d04f521cbb56"));
+
+        let invalid = |id : &str| id.parse::<Mbid>() == Err(IdError::InvalidMbid(id.to_owned()));
+        assert!(invalid("056e4f3ed5054dad8ec1d04f521cbb56"));
+        assert!(invalid("056e4f3e-d505-4dad-8ec1d04f521cbb56"));
+        assert!(invalid("056e4f3-ed505-4dad-8ec1-d04f521cbb56"));
+        assert!(invalid("056e4f3e-d505-4dad-8ec1-d04f521cbb5g"));
+        assert!(invalid("056e4f3e-d505-4dad-8ec1-d04f521cbb56-"));
+        assert!(invalid(""));
+    }
+}
@@ -26,14 +26,60 @@ mod structs;
 mod enums;
 mod client;
 mod builder;
+mod queries;
+mod error;
+mod ids;
+mod deserialize;
+mod pagination;
+mod locator;
+mod dates;
+mod search;
+mod synced;
+mod lrc;
+mod dfxp;
+mod mxm;
+mod richsync;
+mod timing;
+mod cursor;
+mod estimate;
+mod clean;
+mod structure;
+mod align;
+mod bilingual;
+mod translation_set;
+mod export;
+
+#[cfg(test)]
+mod testing;
 
 pub use self::structs::*;
 pub use self::enums::*;
 pub use self::client::*;
 pub use self::builder::*;
+pub use self::queries::*;
+pub use self::error::*;
+pub use self::ids::*;
+pub use self::pagination::*;
+pub use self::locator::*;
+pub use self::dates::*;
+pub use self::synced::*;
+pub use self::export::*;
+pub use self::cursor::*;
+pub use self::estimate::*;
+pub use self::clean::*;
+pub use self::structure::*;
+pub use self::align::*;
+pub use self::bilingual::*;
+pub use self::translation_set::*;
 
 #[cfg(feature ="marcos")]
 mod macros;
 
 #[cfg(feature ="marcos")]
-pub use self::macros::*;
\ No newline at end of file
+pub use self::macros::*;
+
+#[cfg(feature ="detect")]
+mod detect;
+
+#[cfg(feature ="detect")]
+pub use self::detect::*;
@@ -0,0 +1,211 @@
+use crate::{
+    TrackId,
+    CommonTrackId,
+    ArtistId,
+    AlbumId,
+    Isrc,
+    Mbid,
+
+    TrackQuery,
+    ArtistQuery,
+    ArtistAlbumsQuery,
+    RelatedArtistsQuery,
+    AlbumTracksQuery,
+};
+
+/// The different ways of pointing at a track.
+///
+/// Methods taking a `TrackLocator` pick the query key or endpoint matching the variant. Where an endpoint
+/// does not accept the given kind of identifier, the track is looked up first and its commontrack id is used instead.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TrackLocator {
+    /// The Musixmatch track id.
+    ById(TrackId),
+    /// The Musixmatch commontrack id.
+    ByCommonTrackId(CommonTrackId),
+    /// The ISRC of the recording.
+    ByIsrc(Isrc),
+    /// The MusicBrainz recording id.
+    ByMbid(Mbid),
+    /// The song title and artist, matched against the database.
+    ByTitleArtist {
+        /// The song title.
+        title : String,
+        /// The song artist.
+        artist : String,
+    },
+}
+
+impl TrackLocator {
+    /// Creates a locator matching a song by its title and artist.
+    pub fn by_title_artist(title : impl Into<String>,artist : impl Into<String>) -> Self {
+        TrackLocator::ByTitleArtist { title : title.into(), artist : artist.into() }
+    }
+
+    /// The `track.get` query for this track.
+    ///
+    /// A [TrackLocator::ByTitleArtist] carries no identifier, so its query fails validation and has to go through the matcher instead.
+    pub(crate) fn track_query(&self) -> TrackQuery {
+        match self {
+            TrackLocator::ById(id) => TrackQuery::new().track_id(Some(*id)),
+            TrackLocator::ByCommonTrackId(id) => TrackQuery::new().commontrack_id(Some(*id)),
+            TrackLocator::ByIsrc(isrc) => TrackQuery::new().track_isrc(Some(isrc)),
+            TrackLocator::ByMbid(mbid) => TrackQuery::new().track_mbid(Some(mbid)),
+            TrackLocator::ByTitleArtist { .. } => TrackQuery::new(),
+        }
+    }
+}
+
+impl From<TrackId> for TrackLocator {
+    fn from(id : TrackId) -> Self {
+        TrackLocator::ById(id)
+    }
+}
+
+impl From<CommonTrackId> for TrackLocator {
+    fn from(id : CommonTrackId) -> Self {
+        TrackLocator::ByCommonTrackId(id)
+    }
+}
+
+impl From<Isrc> for TrackLocator {
+    fn from(isrc : Isrc) -> Self {
+        TrackLocator::ByIsrc(isrc)
+    }
+}
+
+impl From<Mbid> for TrackLocator {
+    fn from(mbid : Mbid) -> Self {
+        TrackLocator::ByMbid(mbid)
+    }
+}
+
+/// The different ways of pointing at an artist.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ArtistLocator {
+    /// The Musixmatch artist id.
+    ById(ArtistId),
+    /// The MusicBrainz artist id.
+    ByMbid(Mbid),
+}
+
+impl From<ArtistId> for ArtistLocator {
+    fn from(id : ArtistId) -> Self {
+        ArtistLocator::ById(id)
+    }
+}
+
+impl From<Mbid> for ArtistLocator {
+    fn from(mbid : Mbid) -> Self {
+        ArtistLocator::ByMbid(mbid)
+    }
+}
+
+impl ArtistLocator {
+    /// The `artist.get` query for this artist.
+    pub(crate) fn artist_query(&self) -> ArtistQuery {
+        match self {
+            ArtistLocator::ById(id) => ArtistQuery::new().artist_id(Some(*id)),
+            ArtistLocator::ByMbid(mbid) => ArtistQuery::new().artist_mbid(Some(mbid)),
+        }
+    }
+
+    /// The `artist.albums.get` query for this artist.
+    pub(crate) fn albums_query(&self) -> ArtistAlbumsQuery {
+        match self {
+            ArtistLocator::ById(id) => ArtistAlbumsQuery::new().artist_id(Some(*id)),
+            ArtistLocator::ByMbid(mbid) => ArtistAlbumsQuery::new().artist_mbid(Some(mbid)),
+        }
+    }
+
+    /// The `artist.related.get` query for this artist.
+    pub(crate) fn related_artists_query(&self) -> RelatedArtistsQuery {
+        match self {
+            ArtistLocator::ById(id) => RelatedArtistsQuery::new().artist_id(Some(*id)),
+            ArtistLocator::ByMbid(mbid) => RelatedArtistsQuery::new().artist_mbid(Some(mbid)),
+        }
+    }
+}
+
+/// The different ways of pointing at an album.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum AlbumLocator {
+    /// The Musixmatch album id.
+    ById(AlbumId),
+    /// The MusicBrainz release id.
+    ByMbid(Mbid),
+}
+
+impl From<AlbumId> for AlbumLocator {
+    fn from(id : AlbumId) -> Self {
+        AlbumLocator::ById(id)
+    }
+}
+
+impl From<Mbid> for AlbumLocator {
+    fn from(mbid : Mbid) -> Self {
+        AlbumLocator::ByMbid(mbid)
+    }
+}
+
+impl AlbumLocator {
+    /// The `album.tracks.get` query for this album.
+    pub(crate) fn tracks_query(&self) -> AlbumTracksQuery {
+        match self {
+            AlbumLocator::ById(id) => AlbumTracksQuery::new().album_id(Some(*id)),
+            AlbumLocator::ByMbid(mbid) => AlbumTracksQuery::new().album_mbid(Some(mbid)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use api_request_utils::serde_json::{Value, json};
+
+    use super::*;
+    use crate::{testing::{isrc, mbid}, Query};
+
+    fn parameters(query : &impl Query) -> BTreeMap<&'static str,Value> {
+        query.parameters().iter().map(|(key, value)| (*key, value.clone())).collect()
+    }
+
+    #[test]
+    fn ids_convert_into_locators() {
+        assert_eq!(TrackLocator::from(TrackId(1)), TrackLocator::ById(TrackId(1)));
+        assert_eq!(TrackLocator::from(CommonTrackId(2)), TrackLocator::ByCommonTrackId(CommonTrackId(2)));
+        assert_eq!(TrackLocator::from(isrc()), TrackLocator::ByIsrc(isrc()));
+        assert_eq!(TrackLocator::from(mbid()), TrackLocator::ByMbid(mbid()));
+        assert_eq!(TrackLocator::by_title_artist("Veridis Quo", String::from("Daft Punk")), TrackLocator::ByTitleArtist { title : "Veridis Quo".to_owned(), artist : "Daft Punk".to_owned() });
+
+        assert_eq!(ArtistLocator::from(ArtistId(118)), ArtistLocator::ById(ArtistId(118)));
+        assert_eq!(ArtistLocator::from(mbid()), ArtistLocator::ByMbid(mbid()));
+        assert_eq!(AlbumLocator::from(AlbumId(9)), AlbumLocator::ById(AlbumId(9)));
+        assert_eq!(AlbumLocator::from(mbid()), AlbumLocator::ByMbid(mbid()));
+    }
+
+    #[test]
+    fn track_locators_pick_their_parameter() {
+        let track = |locator : TrackLocator| parameters(&locator.track_query());
+        assert_eq!(track(TrackId(1).into()), BTreeMap::from([("track_id", json!(1))]));
+        assert_eq!(track(CommonTrackId(2).into()), BTreeMap::from([("commontrack_id", json!(2))]));
+        assert_eq!(track(isrc().into()), BTreeMap::from([("track_isrc", json!("USVI20400001"))]));
+        assert_eq!(track(mbid().into()), BTreeMap::from([("track_mbid", json!("056e4f3e-d505-4dad-8ec1-d04f521cbb56"))]));
+        assert!(TrackLocator::by_title_artist("Veridis Quo", "Daft Punk").track_query().build().is_err());
+    }
+
+    #[test]
+    fn artist_and_album_locators_pick_their_parameter() {
+        let by_id = BTreeMap::from([("artist_id", json!(118))]);
+        let by_mbid = BTreeMap::from([("artist_mbid", json!("056e4f3e-d505-4dad-8ec1-d04f521cbb56"))]);
+        for (locator, expected) in [(ArtistLocator::from(ArtistId(118)), &by_id), (ArtistLocator::from(mbid()), &by_mbid)] {
+            assert_eq!(&parameters(&locator.artist_query()), expected);
+            assert_eq!(&parameters(&locator.albums_query()), expected);
+            assert_eq!(&parameters(&locator.related_artists_query()), expected);
+        }
+
+        assert_eq!(parameters(&AlbumLocator::from(AlbumId(9)).tracks_query()), BTreeMap::from([("album_id", json!(9))]));
+        assert_eq!(parameters(&AlbumLocator::from(mbid()).tracks_query()), BTreeMap::from([("album_mbid", json!("056e4f3e-d505-4dad-8ec1-d04f521cbb56"))]));
+    }
+}
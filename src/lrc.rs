@@ -0,0 +1,272 @@
+use std::{fmt::Write, time::Duration};
+
+use crate::{SyncedLine, SyncedLyrics, SyncedLyricsError};
+
+/// Parses an LRC timestamp such as `01:23.45`, `01:23.456`, `01:23:45` or `01:23`.
+pub(crate) fn parse_timestamp(value : &str) -> Option<Duration> {
+    let (minutes,rest) = value.split_once(':')?;
+    let (seconds,fraction) = match rest.split_once(['.',':']) {
+        Some((seconds,fraction)) => (seconds,Some(fraction)),
+        None => (rest,None),
+    };
+
+    let digits = |s : &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !digits(minutes) || seconds.len() != 2 || !digits(seconds) {
+        return None;
+    }
+
+    let millis = match fraction {
+        None => 0,
+        Some(fraction) if (1..=3).contains(&fraction.len()) && digits(fraction) => fraction.parse::<u64>().ok()? * 10u64.pow(3 - fraction.len() as u32),
+        Some(_) => return None,
+    };
+
+    let seconds : u64 = seconds.parse().ok().filter(|seconds| *seconds < 60)?;
+    Some(Duration::from_millis(minutes.parse::<u64>().ok()?.checked_mul(60_000)?.checked_add(seconds * 1000 + millis)?))
+}
+
+/// Formats a time as an LRC timestamp, using hundredths of a second unless that would lose precision.
+pub(crate) fn format_timestamp(time : Duration) -> String {
+    let millis = time.as_millis();
+    let (minutes,seconds,millis) = (millis / 60_000,millis / 1000 % 60,millis % 1000);
+    match millis % 10 {
+        0 => format!("{minutes:02}:{seconds:02}.{:02}",millis / 10),
+        _ => format!("{minutes:02}:{seconds:02}.{millis:03}"),
+    }
+}
+
+/// The content of a single line of an LRC file.
+enum LrcLine<'s> {
+    Blank,
+    Tag(&'s str,&'s str),
+    Lyric(Vec<Duration>,&'s str),
+}
+
+/// Reads one line of an LRC file, returning `None` if it is malformed.
+fn parse_line(line : &str) -> Option<LrcLine<'_>> {
+    let mut rest = line.trim();
+    if rest.is_empty() {
+        return Some(LrcLine::Blank);
+    }
+
+    let mut times = Vec::new();
+    while let Some(group) = rest.strip_prefix('[') {
+        let (content,after) = group.split_once(']')?;
+
+        match parse_timestamp(content.trim()) {
+            Some(time) => times.push(time),
+            None if times.is_empty() && after.trim().is_empty() => {
+                let (name,value) = content.split_once(':')?;
+                let name = name.trim();
+                return match !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                    true => Some(LrcLine::Tag(name,value.trim())),
+                    false => None,
+                }
+            },
+            // Bracketed text after the timestamps, e.g. `[00:12.00][Chorus]`, belongs to the line.
+            None if !times.is_empty() => break,
+            None => return None,
+        }
+        rest = after;
+    }
+
+    match times.is_empty() {
+        true => None,
+        false => Some(LrcLine::Lyric(times,rest.trim())),
+    }
+}
+
+impl SyncedLyrics {
+    /// Parses lyrics in the LRC format, skipping malformed lines.
+    ///
+    /// Metadata tags such as `[ar:Daft Punk]` are kept in [SyncedLyrics::tags]. A line with several
+    /// timestamps, e.g. `[00:12.00][01:30.00]chorus`, yields a line for each of them. Enhanced LRC
+    /// word timestamps (`<00:12.50>`) are left in the text.
+    pub fn from_lrc(lrc : &str) -> Self {
+        let mut lyrics = SyncedLyrics::default();
+        for line in lrc.lines().filter_map(parse_line) {
+            lyrics.add_lrc_line(line);
+        }
+        lyrics.lines.sort_by_key(|line| *line.time());
+        lyrics
+    }
+
+    /// Parses lyrics in the LRC format like [SyncedLyrics::from_lrc], but fails on the first malformed line.
+    pub fn try_from_lrc(lrc : &str) -> Result<Self,SyncedLyricsError> {
+        let mut lyrics = SyncedLyrics::default();
+        for (index,line) in lrc.lines().enumerate() {
+            let parsed = parse_line(line).ok_or_else(|| SyncedLyricsError::MalformedLine { line : index + 1, content : line.to_owned() })?;
+            lyrics.add_lrc_line(parsed);
+        }
+        lyrics.lines.sort_by_key(|line| *line.time());
+        Ok(lyrics)
+    }
+
+    fn add_lrc_line(&mut self,line : LrcLine<'_>) {
+        match line {
+            LrcLine::Blank => {},
+            LrcLine::Tag(name,value) => self.set_tag(name,value),
+            LrcLine::Lyric(times,text) => self.lines.extend(times.into_iter().map(|time| SyncedLine::new(time,text))),
+        }
+    }
+
+    /// Writes the lyrics in the LRC format, the tags first and then one line per timestamp.
+    ///
+    /// Parsing the result with [SyncedLyrics::from_lrc] gives back lyrics equal to these, except for
+    /// end times and words, which LRC does not record, and rows of a line, which are joined by a space.
+    pub fn to_lrc(&self) -> String {
+        let mut lrc = String::new();
+        for (name,value) in self.tags() {
+            let _ = writeln!(lrc,"[{name}:{value}]");
+        }
+        for line in self.lines() {
+            let _ = match line.text().is_empty() {
+                true => writeln!(lrc,"[{}]",format_timestamp(*line.time())),
+                false => writeln!(lrc,"[{}] {}",format_timestamp(*line.time()),line.text().replace('\n'," ")),
+            };
+        }
+        lrc
+    }
+
+    /// Writes the lyrics in the Enhanced LRC (A2) format, e.g. `[00:12.00] <00:12.00> Work <00:12.50> it <00:13.00>`.
+    ///
+    /// Lines without timed words, such as those of a [Subtitle](crate::Subtitle), are written as a single
+    /// word lasting the whole line. A line ends at its own end time or else when the next line starts;
+    /// the last line of lyrics without end times has no closing word tag. Use [SyncedLyrics::set_track_tags]
+    /// to fill the headers from a track.
+    pub fn to_enhanced_lrc(&self) -> String {
+        let mut lrc = String::new();
+        for (name,value) in self.tags() {
+            let _ = writeln!(lrc,"[{name}:{value}]");
+        }
+        for (index,line) in self.lines().iter().enumerate() {
+            let start = *line.time();
+            let _ = write!(lrc,"[{}]",format_timestamp(start));
+
+            let text = line.text().replace('\n'," ");
+            if !text.trim().is_empty() {
+                match line.words().is_empty() {
+                    true => { let _ = write!(lrc," <{}> {}",format_timestamp(start),text.trim()); },
+                    false => for word in line.words() {
+                        let _ = write!(lrc," <{}> {}",format_timestamp(*word.time()),word.text());
+                    },
+                }
+
+                let end = line.end().or_else(|| self.lines()[index + 1..].iter().map(|next| *next.time()).find(|next| *next > start));
+                if let Some(end) = end {
+                    let _ = write!(lrc," <{}>",format_timestamp(end));
+                }
+            }
+            lrc.push('\n');
+        }
+        lrc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SyncedWord, Track};
+    use api_request_utils::serde_json::{self, json};
+
+    const LRC : &str = "[ar:Daft Punk]
+[ti:Harder, Better, Faster, Stronger]
+[length: 03:44]
+[offset:+250]
+
+[00:12.00][01:30.5]Work it
+[00:13.345]Make it
+[00:14.00]
+not a lyric line
+[00:15:20] Do it
+[00:16.00][Chorus]
+";
+
+    #[test]
+    fn parses_tags_and_lines() {
+        let lyrics = SyncedLyrics::from_lrc(LRC);
+
+        assert_eq!(lyrics.artist(), Some("Daft Punk"));
+        assert_eq!(lyrics.title(), Some("Harder, Better, Faster, Stronger"));
+        assert_eq!(lyrics.length(), Some(Duration::from_secs(224)));
+        assert_eq!(lyrics.offset(), Some(250));
+        assert_eq!(lyrics.lines(), &[
+            SyncedLine::new(Duration::from_millis(12_000), "Work it"),
+            SyncedLine::new(Duration::from_millis(13_345), "Make it"),
+            SyncedLine::new(Duration::from_millis(14_000), ""),
+            SyncedLine::new(Duration::from_millis(15_200), "Do it"),
+            SyncedLine::new(Duration::from_millis(16_000), "[Chorus]"),
+            SyncedLine::new(Duration::from_millis(90_500), "Work it"),
+        ]);
+    }
+
+    #[test]
+    fn strict_parsing_reports_the_malformed_line() {
+        assert_eq!(SyncedLyrics::try_from_lrc(LRC), Err(SyncedLyricsError::MalformedLine { line : 9, content : "not a lyric line".to_owned() }));
+        assert_eq!(SyncedLyrics::try_from_lrc("[00:12.00]a\n[0x:12.00]b").unwrap_err(), SyncedLyricsError::MalformedLine { line : 2, content : "[0x:12.00]b".to_owned() });
+    }
+
+    #[test]
+    fn huge_times_are_rejected_instead_of_overflowing() {
+        assert_eq!(parse_timestamp("307445734561825:59.99"), None);
+        assert_eq!(parse_timestamp("307445734561826:00.00"), None);
+        assert_eq!(parse_timestamp("307445734561825:00.00"), Some(Duration::from_millis(18_446_744_073_709_500_000)));
+        assert!(SyncedLyrics::try_from_lrc("[307445734561825:59.99]a").is_err());
+
+        assert_eq!(SyncedLyrics::from_lrc("[length:307445734561825860:59]").length(), None);
+        assert_eq!(SyncedLyrics::from_lrc("[length:307445734561825862:00]").length(), None);
+        assert_eq!(SyncedLyrics::from_lrc("[length:307445734561825860:00]").length(), Some(Duration::from_secs(18_446_744_073_709_551_600)));
+    }
+
+    #[test]
+    fn writing_round_trips() {
+        let lyrics = SyncedLyrics::from_lrc(LRC);
+        let lrc = lyrics.to_lrc();
+
+        assert!(lrc.starts_with("[ar:Daft Punk]\n[ti:Harder, Better, Faster, Stronger]\n[length:03:44]\n[offset:+250]\n[00:12.00] Work it\n[00:13.345] Make it\n[00:14.00]\n"));
+        assert_eq!(SyncedLyrics::from_lrc(&lrc), lyrics);
+    }
+
+    #[test]
+    fn writes_enhanced_lrc() {
+        let richsync = r#"[
+            {"ts":12,"te":13.3,"l":[{"c":"Work","o":0},{"c":" ","o":0.4},{"c":"it","o":0.5}],"x":"Work it"},
+            {"ts":13.345,"te":14,"l":[{"c":"Make","o":0},{"c":" ","o":0.3},{"c":"it","o":0.355}],"x":"Make it"}
+        ]"#;
+        let mut lyrics = SyncedLyrics::from_richsync(richsync).unwrap();
+        lyrics.set_tag("ar", "Daft Punk");
+
+        assert_eq!(lyrics.lines()[0].words(), &[SyncedWord::new(Duration::from_millis(12_000), "Work"), SyncedWord::new(Duration::from_millis(12_500), "it")]);
+        assert_eq!(lyrics.to_enhanced_lrc(), "[ar:Daft Punk]
+[00:12.00] <00:12.00> Work <00:12.50> it <00:13.30>
+[00:13.345] <00:13.345> Make <00:13.70> it <00:14.00>
+");
+
+        assert_eq!(SyncedLyrics::from_lrc(LRC).to_enhanced_lrc().lines().skip(4).take(4).collect::<Vec<_>>(), [
+            "[00:12.00] <00:12.00> Work it <00:13.345>",
+            "[00:13.345] <00:13.345> Make it <00:14.00>",
+            "[00:14.00]",
+            "[00:15.20] <00:15.20> Do it <00:16.00>",
+        ]);
+        assert!(SyncedLyrics::from_lrc(LRC).to_enhanced_lrc().ends_with("[01:30.50] <01:30.50> Work it\n"));
+    }
+
+    #[test]
+    fn writes_track_tags() {
+        let track : Track = serde_json::from_value(json!({
+            "track_id" : 15953433, "track_name" : "Harder, Better, Faster, Stronger", "track_rating" : 70, "num_favourite" : 1200,
+            "commontrack_id" : 1406962, "instrumental" : false, "explicit" : false, "has_lyrics" : true, "has_subtitles" : true, "has_richsync" : true,
+            "album_id" : 13790012, "album_name" : "Discovery", "artist_id" : 1435, "artist_name" : "Daft Punk",
+            "track_share_url" : "", "track_edit_url" : "", "restricted" : false, "updated_time" : "2017-08-22T08:19:51Z",
+            "primary_genres" : [], "track_name_translation_list" : []
+        })).unwrap();
+        let mut lyrics = SyncedLyrics::from_lrc("[00:12.00]Work it");
+        lyrics.set_track_tags(&track);
+
+        assert_eq!(lyrics.to_enhanced_lrc(), "[ar:Daft Punk]
+[ti:Harder, Better, Faster, Stronger]
+[al:Discovery]
+[00:12.00] <00:12.00> Work it
+");
+    }
+}
@@ -12,132 +12,118 @@ use crate::{
 
     SubtitleFormat, 
     Chart,
+    Country,
+    Language,
     SortBy,
 
     Artist,
     Track,
     Lyrics,
     Subtitle,
+    MatchedSubtitle,
     Album,
+    BilingualLyrics,
+    TranslationSet,
 
-    TrackSearchQuery
+    ArtistId,
+    Mbid,
+
+    TrackLocator,
+    ArtistLocator,
+    AlbumLocator,
+
+    PartialDate,
+
+    TrackSearchQuery,
+    Query,
+    Page
 };
 
 
 default_args! { 
-    export pub async fn top_artists_by_country<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,country : Option<&str> = None,page : Option<u16>  = None,page_size : Option<u8>  = None) -> Option<Vec<Artist>> {
+    export pub async fn top_artists_by_country<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,country : Option<Country> = None,page : Option<u32>  = None,page_size : Option<u8>  = None) -> Option<Page<Artist>> {
         musicabgleich.top_artists_by_country(country,page,page_size).await
     }
 }
 
 default_args! { 
-    export pub async fn top_tracks_by_country<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,country : Option<&str> = None,chart_name : Option<Chart> = None,has_lyrics : Option<bool> = None,page : Option<u16> = None,page_size : Option<u8> = None) -> Option<Vec<Track>> {
+    export pub async fn top_tracks_by_country<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,country : Option<Country> = None,chart_name : Option<Chart> = None,has_lyrics : Option<bool> = None,page : Option<u32> = None,page_size : Option<u8> = None) -> Option<Page<Track>> {
         musicabgleich.top_tracks_by_country(country,chart_name,has_lyrics,page,page_size).await
     }
 }
 
 default_args! { 
-    export pub async fn track<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,title : Option<&str> = None,artist : Option<&str> = None,album : Option<&str> = None) -> Option<Track> {
-        musicabgleich.track(title,artist,album).await
-    }
-}
-
-default_args! { 
-    export pub async fn track_lyrics<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,title : Option<&str> = None,artist : Option<&str> = None) -> Option<Lyrics> {
-        musicabgleich.track_lyrics(title,artist).await
-    }
-}
-
-default_args! { 
-    export pub async fn track_subtitle<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,commontrack_id : u32 = None,subtitle_length/*seconds*/ : Option<u16> = None,max_deviation : Option<u8> /*seconds*/ = None,format : Option<SubtitleFormat> = None) -> Option<Subtitle> {
-        musicabgleich.track_subtitle(commontrack_id,subtitle_length,max_deviation,format).await
+    export pub async fn track<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : TrackLocator) -> Option<Track> {
+        musicabgleich.track(locator).await
     }
 }
 
 default_args! { 
-    export pub async fn subtitle<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,title : Option<&str> = None,artist : Option<&str> = None,album : Option<&str> = None,subtitle_length/*seconds*/ : Option<u16> = None,max_deviation : Option<u8> /*seconds*/ = None) -> Option<Track> {
-        musicabgleich.subtitle(title,artist,album,subtitle_length,max_deviation).await
+    export pub async fn track_lyrics<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : TrackLocator) -> Option<Lyrics> {
+        musicabgleich.track_lyrics(locator).await
     }
 }
 
 default_args! { 
-    export pub async fn track_lyrics_translations_with_track_irsc<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id: &str,min_completed : Option<f32> = None /*percent*/,selected_language : Option<&str> = None/* (ISO 639-1) */) -> Option<Lyrics> { 
-        musicabgleich.track_lyrics_translations_with_track_irsc(id,min_completed,selected_language).await
+    export pub async fn track_subtitle<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : TrackLocator,subtitle_length/*seconds*/ : Option<u16> = None,max_deviation : Option<u8> /*seconds*/ = None,format : Option<SubtitleFormat> = None) -> Option<Subtitle> {
+        musicabgleich.track_subtitle(locator,subtitle_length,max_deviation,format).await
     }
 }
 
 default_args! { 
-    export pub async fn track_lyrics_translations_with_musixbrainx_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id: &str,min_completed : Option<f32> = None /*percent*/,selected_language : Option<&str> = None/* (ISO 639-1) */) -> Option<Lyrics> { 
-        musicabgleich.track_lyrics_translations_with_musixbrainx_id(id,min_completed,selected_language).await
+    export pub async fn subtitle<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,title : Option<&str> = None,artist : Option<&str> = None,album : Option<&str> = None,subtitle_length/*seconds*/ : Option<u16> = None,max_deviation : Option<u8> /*seconds*/ = None,format : Option<SubtitleFormat> = None) -> Option<MatchedSubtitle> {
+        musicabgleich.subtitle(title,artist,album,subtitle_length,max_deviation,format).await
     }
 }
 
 default_args! { 
-    export pub async fn track_lyrics_translations_with_track_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id: &str,min_completed : Option<f32> = None/*percent*/,selected_language : Option<&str> = None/* (ISO 639-1) */) -> Option<Lyrics> { 
-        musicabgleich.track_lyrics_translations_with_track_id(id,min_completed,selected_language).await
+    export pub async fn track_lyrics_translations<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : TrackLocator,min_completed : Option<f32> = None /*0 to 1*/,selected_language : Option<&Language> = None) -> Option<Lyrics> { 
+        musicabgleich.track_lyrics_translations(locator,min_completed,selected_language).await
     }
 }
 
 default_args! { 
-    export pub async fn track_lyrics_translations_with_commontrack_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id: &str,min_completed : Option<f32> = None /*percent*/,selected_language : Option<&str> = None/* (ISO 639-1) */) -> Option<Lyrics> { 
-        musicabgleich.track_lyrics_translations_with_commontrack_id(id,min_completed,selected_language).await
+    export pub async fn track_subtitle_translations<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : TrackLocator,min_completed : Option<f32> = None /*0 to 1*/,selected_language : Option<&Language> = None,subtitle_length/*seconds*/ : Option<u16> = None,max_deviation : Option<u8> = None /*seconds*/)-> Option<Subtitle> {
+        musicabgleich.track_subtitle_translations(locator,min_completed,selected_language,subtitle_length,max_deviation).await
     }
 }
 
 default_args! { 
-    export pub async fn track_subtitle_translations_with_track_isrc<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id: &str,min_completed : Option<f32> = None /*percent*/,selected_language : Option<&str> = None/* (ISO 639-1) */,subtitle_length/*seconds*/ : Option<u16> = None,max_deviation : Option<u8> = None /*seconds*/)-> Option<Subtitle> {
-        musicabgleich.track_subtitle_translations_with_track_isrc(id,min_completed,selected_language,subtitle_length,max_deviation).await
+    export pub async fn track_lyrics_translation_set<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : TrackLocator,languages : &[Language],min_completed : Option<f32> = None /*0 to 1*/) -> Option<TranslationSet> {
+        musicabgleich.track_lyrics_translation_set(locator,languages,min_completed).await
     }
 }
 
 default_args! { 
-    export pub async fn track_subtitle_translations_with_commontrack_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id: &str,min_completed : Option<f32> = None /*percent*/,selected_language : Option<&str> = None/* (ISO 639-1) */,subtitle_length/*seconds*/ : Option<u16> = None,max_deviation : Option<u8> = None /*seconds*/)-> Option<Subtitle> {
-        musicabgleich.track_subtitle_translations_with_commontrack_id(id,min_completed,selected_language,subtitle_length,max_deviation).await
+    export pub async fn track_bilingual_subtitle<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : TrackLocator,selected_language : &Language,subtitle_length/*seconds*/ : Option<u16> = None,max_deviation : Option<u8> = None /*seconds*/)-> Option<BilingualLyrics> {
+        musicabgleich.track_bilingual_subtitle(locator,selected_language,subtitle_length,max_deviation).await
     }
 }
 
 default_args! { 
-    export pub async fn search_artist<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, artist_song: Option<&str> = None, artist_id: Option<u32> = None, artist_mbid: Option<&str> = None, page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Artist> {
+    export pub async fn search_artist<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, artist_song: Option<&str> = None, artist_id: Option<ArtistId> = None, artist_mbid: Option<&Mbid> = None, page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Page<Artist>> {
         musicabgleich.search_artist(artist_song,artist_id,artist_mbid,page,page_size).await
     }
 }
 
 default_args! { 
-    export pub async fn artist_relating_albums_with_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id:u32 ,album_name: Option<bool> = None,release_date_sort: Option<SortBy> = None, page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Vec<Album>> {
-        musicabgleich.artist_relating_albums_with_id(id,album_name,release_date_sort,page,page_size).await
+    export pub async fn artist_relating_albums<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : ArtistLocator,album_name: Option<bool> = None,release_date_sort: Option<SortBy> = None, page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Page<Album>> {
+        musicabgleich.artist_relating_albums(locator,album_name,release_date_sort,page,page_size).await
     }
 }
 
 default_args! { 
-    export pub async fn artist_relating_albums_with_musixbrainz_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id:u32 ,album_name: Option<bool> = None,release_date_sort: Option<SortBy> = None, page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Vec<Album>> {
-        musicabgleich.artist_relating_albums_with_musixbrainz_id(id,album_name,release_date_sort,page,page_size).await
+    export pub async fn artist_relating_artist<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : ArtistLocator,page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Page<Artist>> {
+        musicabgleich.artist_relating_artist(locator,page,page_size).await
     }
 }
 
 default_args! { 
-    export pub async fn artist_relating_artist_with_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,id:u32,page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Vec<Artist>> {
-        musicabgleich.artist_relating_artist_with_id(id,page,page_size).await
-    }
-}
-
-default_args! { 
-    export pub async fn artist_relating_artist_with_musixbrainz_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,id:u32,page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Vec<Artist>> {
-        musicabgleich.artist_relating_artist_with_musixbrainz_id(id,page,page_size).await
-    }
-}
-
-default_args! { 
-    export async fn album_tracks_with_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id: u32 , has_lyrics: Option<bool> = None, page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Vec<Track>> {
-        musicabgleich.album_tracks_with_id(id,has_lyrics,page,page_size).await
+    export async fn album_tracks<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>,locator : AlbumLocator, has_lyrics: Option<bool> = None, page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Page<Track>> {
+        musicabgleich.album_tracks(locator,has_lyrics,page,page_size).await
     } 
 }
 
-default_args! { 
-    export async fn album_tracks_with_musixbrainz_id<F : Fn(RequestError<Value>) + Sync + Send>(musicabgleich : &MusixAbgleich<'a,F>, id: u32 , has_lyrics: Option<bool> = None, page: Option<u32> = None, page_size: Option<u8> = None) -> Option<Vec<Track>> {
-        musicabgleich.album_tracks_with_musixbrainz_id(id,has_lyrics,page,page_size).await
-    }     
-}
-
 default_args!{
     export async fn search_track<F : Fn(RequestError<Value>) + Sync + Send>(
         musicabgleich : &MusixAbgleich<'a,F>,
@@ -147,18 +133,18 @@ default_args!{
         song_title_or_artist_contains_word: Option<&str>,
         search_writers: Option<&str>,
         search_all_contains_word: Option<&str>,
-        artist_id: Option<u32>,
+        artist_id: Option<ArtistId>,
         music_genre_id: Option<u32>,
-        lyrics_language: Option<&str>,
+        lyrics_language: Option<&Language>,
         has_lyrics: Option<bool>,
-        track_release_group_first_release_date_min: Option<&str>,
-        track_release_group_first_release_date_max: Option<&str>,
+        track_release_group_first_release_date_min: Option<PartialDate>,
+        track_release_group_first_release_date_max: Option<PartialDate>,
         artist_rating: Option<SortBy>,
         track_rating: Option<SortBy>,
         quorum_factor: Option<f32>,
-        page: Option<u8>,
+        page: Option<u32>,
         page_size: Option<u32>
-    ) -> Option<Track> {
+    ) -> Option<Page<Track>> {
         let query = TrackSearchQuery::new()
             .song_title(song_title)
             .song_artist(song_artist)
@@ -176,7 +162,9 @@ default_args!{
             .track_rating(track_rating)
             .quorum_factor(quorum_factor)
             .page(page)
-            .page_size(page_size);
+            .page_size(page_size)
+            .build()
+            .ok()?;
 
         musicabgleich.search_track(query).await
     }
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use api_request_utils::serde::Deserialize;
+use api_request_utils::serde_json;
+
+use crate::{SyncedLine, SyncedLyrics, SyncedLyricsError};
+
+/// A line of a subtitle body in the `mxm` format.
+#[derive(Deserialize)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+struct MxmLine {
+    text : String,
+    time : MxmTime,
+}
+
+/// The start of a line, given both as the total in seconds and split into its parts.
+#[derive(Deserialize)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+struct MxmTime {
+    total : Option<f64>,
+    #[serde(default)]
+    minutes : u64,
+    #[serde(default)]
+    seconds : u64,
+    #[serde(default)]
+    hundredths : u64,
+}
+
+impl MxmTime {
+    /// The time, with the `total` rounded to the millisecond as it is an `f64` that rarely holds the exact time.
+    fn duration(&self) -> Result<Duration,SyncedLyricsError> {
+        match self.total {
+            Some(total) => {
+                let millis = (total * 1000.0).round();
+                (millis.is_finite() && millis >= 0.0 && millis < u64::MAX as f64)
+                    .then(|| Duration::from_millis(millis as u64))
+                    .ok_or_else(|| SyncedLyricsError::InvalidTime(total.to_string()))
+            },
+            None => self.minutes.checked_mul(60_000)
+                .and_then(|millis| millis.checked_add(self.seconds.checked_mul(1000)?))
+                .and_then(|millis| millis.checked_add(self.hundredths.checked_mul(10)?))
+                .map(Duration::from_millis)
+                .ok_or_else(|| SyncedLyricsError::InvalidTime(format!("{}:{:02}.{:02}",self.minutes,self.seconds,self.hundredths))),
+        }
+    }
+}
+
+/// Whether `body` is a JSON array rather than LRC, which starts with a bracketed tag or timestamp too.
+pub(crate) fn is_mxm(body : &str) -> bool {
+    body.trim_start().strip_prefix('[').is_some_and(|rest| rest.trim_start().starts_with(['{',']']))
+}
+
+impl SyncedLyrics {
+    /// Parses a subtitle body in the `mxm` format, a JSON array such as
+    /// `[{"text":"Work it","time":{"total":12.5,"minutes":0,"seconds":12,"hundredths":50}}]`.
+    ///
+    /// The `total` in seconds is used when present, otherwise the time is put together from its parts.
+    pub fn from_mxm(body : &str) -> Result<Self,SyncedLyricsError> {
+        let lines : Vec<MxmLine> = serde_json::from_str(body).map_err(|error| SyncedLyricsError::InvalidMxm(error.to_string()))?;
+        let lines = lines.into_iter()
+            .map(|line| Ok(SyncedLine::new(line.time.duration()?,line.text)))
+            .collect::<Result<_,SyncedLyricsError>>()?;
+        Ok(SyncedLyrics::new(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mxm_bodies() {
+        let body = r#"[
+            {"text":"Make it","time":{"total":13.35,"minutes":0,"seconds":13,"hundredths":35}},
+            {"text":"Work it","time":{"total":12.5,"minutes":0,"seconds":12,"hundredths":50}},
+            {"text":"","time":{"minutes":1,"seconds":2,"hundredths":3}}
+        ]"#;
+
+        assert!(is_mxm(body) && is_mxm("[]") && !is_mxm("[00:12.00]Work it"));
+        assert_eq!(SyncedLyrics::from_mxm(body).unwrap().lines(), &[
+            SyncedLine::new(Duration::from_millis(12_500), "Work it"),
+            SyncedLine::new(Duration::from_millis(13_350), "Make it"),
+            SyncedLine::new(Duration::from_millis(62_030), ""),
+        ]);
+        assert!(matches!(SyncedLyrics::from_mxm(r#"[{"text":"Work it"}]"#), Err(SyncedLyricsError::InvalidMxm(_))));
+        assert_eq!(SyncedLyrics::from_mxm(r#"[{"text":"Work it","time":{"total":-1.0}}]"#), Err(SyncedLyricsError::InvalidTime("-1".to_owned())));
+    }
+
+    #[test]
+    fn rejects_parts_that_overflow() {
+        assert_eq!(SyncedLyrics::from_mxm(r#"[{"text":"Work it","time":{"minutes":307445734561826}}]"#), Err(SyncedLyricsError::InvalidTime("307445734561826:00.00".to_owned())));
+        assert_eq!(SyncedLyrics::from_mxm(r#"[{"text":"Work it","time":{"minutes":307445734561825,"seconds":59}}]"#), Err(SyncedLyricsError::InvalidTime("307445734561825:59.00".to_owned())));
+        assert!(SyncedLyrics::from_mxm(r#"[{"text":"Work it","time":{"hundredths":18446744073709551615}}]"#).is_err());
+        assert_eq!(SyncedLyrics::from_mxm(r#"[{"text":"Work it","time":{"total":1e17}}]"#), Err(SyncedLyricsError::InvalidTime("100000000000000000".to_owned())));
+        assert!(SyncedLyrics::from_mxm(r#"[{"text":"Work it","time":{"total":1e16}}]"#).is_ok());
+    }
+}
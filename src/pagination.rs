@@ -0,0 +1,353 @@
+use std::{future::Future, sync::Arc};
+
+use api_request_utils::{
+    RequestError,
+    serde_json::Value,
+    serde::{Deserialize,Serialize},
+};
+
+use getset::Getters;
+
+use futures::{
+    future::BoxFuture,
+    stream::{self, BoxStream},
+    FutureExt,
+    StreamExt,
+};
+
+use crate::{
+    MusixAbgleich,
+
+    Chart,
+    Country,
+    SortBy,
+
+    Artist,
+    Track,
+    Album,
+
+    ArtistId,
+    Mbid,
+
+    ArtistLocator,
+    AlbumLocator,
+
+    PagedQuery,
+    Validated,
+    TrackSearchQuery,
+    ChartArtistsQuery,
+    ChartTracksQuery,
+    ArtistSearchQuery,
+};
+
+/// A single page of a list endpoint together with the pagination metadata returned alongside it.
+#[derive(Getters)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, PartialOrd, Clone)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+pub struct Page<T> {
+    /// The items on this page.
+    #[getset(get = "pub")]
+    items : Vec<T>,
+
+    /// The number of this page, starting at 1.
+    #[getset(get = "pub")]
+    page : u32,
+
+    /// The number of items requested per page.
+    #[getset(get = "pub")]
+    page_size : u32,
+
+    /// The total number of items matching the request (`header.available`), if the endpoint reports it.
+    #[getset(get = "pub")]
+    available : Option<u32>,
+
+    /// Whether a following page holds more items.
+    #[getset(get = "pub")]
+    has_next : bool,
+}
+
+impl<T> Page<T> {
+    pub(crate) fn new(items : Vec<T>,page : u32,page_size : u32,available : Option<u32>) -> Self {
+        let has_next = match available {
+            Some(available) => u64::from(page) * u64::from(page_size) < u64::from(available),
+            None => items.len() as u64 >= u64::from(page_size),
+        } && !items.is_empty();
+
+        Page { items, page, page_size, available, has_next }
+    }
+
+    /// The total number of pages, if the endpoint reports how many items are available.
+    pub fn total_pages(&self) -> Option<u32> {
+        match self.page_size {
+            0 => None,
+            page_size => self.available.map(|available| available.div_ceil(page_size)),
+        }
+    }
+
+    /// Consumes the page, returning its items.
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+type Throttle = Arc<dyn Fn() -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// Options controlling how a paginated stream walks through the pages of an endpoint.
+///
+/// Pages are only requested once every item of the previous page has been consumed, so a stream
+/// never runs ahead of its consumer. The stream ends after the last page reported by the API, when a
+/// request fails, or when [Pagination::max_items] items have been yielded.
+#[derive(Clone)]
+pub struct Pagination {
+    start_page : u32,
+    page_size : u8,
+    max_items : Option<usize>,
+    throttle : Option<Throttle>,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Pagination {
+            start_page : 1,
+            page_size : 100,
+            max_items : None,
+            throttle : None
+        }
+    }
+}
+
+impl Pagination {
+    /// Creates a new `Pagination` starting at page 1 with the maximum page size of 100.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the page to start fetching from.
+    pub fn start_page(mut self, start_page : u32) -> Self {
+        self.start_page = start_page.max(1);
+        self
+    }
+
+    /// Set the number of items requested per page. Range is 1 to 100.
+    pub fn page_size(mut self, page_size : u8) -> Self {
+        self.page_size = page_size.clamp(1, 100);
+        self
+    }
+
+    /// Stop the stream after this many items have been yielded.
+    pub fn max_items(mut self, max_items : usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Await the given future before every page request after the first one.
+    ///
+    /// This is the hook for rate limiting, e.g. `|| tokio::time::sleep(Duration::from_millis(500))`
+    /// or a call into a token bucket shared with the rest of the application.
+    pub fn throttle<Fut>(mut self, throttle : impl Fn() -> Fut + Send + Sync + 'static) -> Self where Fut : Future<Output = ()> + Send + 'static {
+        self.throttle = Some(Arc::new(move || throttle().boxed()));
+        self
+    }
+
+    /// Turns a page fetcher into a stream of the items of every page.
+    ///
+    /// `fetch` receives the page number and the page size and resolves to that page.
+    pub(crate) fn stream<'s, T, Fut>(self, fetch : impl FnMut(u32, u8) -> Fut + Send + 's) -> BoxStream<'s, T> where T : Send + 's, Fut : Future<Output = Option<Page<T>>> + Send + 's {
+        let Pagination { start_page, page_size, max_items, throttle } = self;
+
+        let pages = stream::unfold((fetch, start_page, false), move |(mut fetch, page, exhausted)| {
+            let throttle = throttle.clone();
+            async move {
+                if exhausted {
+                    return None;
+                }
+
+                if let Some(throttle) = throttle.filter(|_| page != start_page) {
+                    throttle().await;
+                }
+
+                let page = fetch(page, page_size).await.filter(|page| !page.items.is_empty())?;
+                let exhausted = !page.has_next;
+                let next = page.page + 1;
+
+                Some((page.items, (fetch, next, exhausted)))
+            }
+        });
+
+        pages.flat_map(stream::iter)
+            .take(max_items.unwrap_or(usize::MAX))
+            .boxed()
+    }
+}
+
+/// Streaming versions of the paginated endpoints.
+///
+/// Each method takes the same filters as its paged counterpart, minus `page` and `page_size`
+/// which are driven by the given [Pagination].
+impl<'a, F> MusixAbgleich<'a, F> where F : Fn(RequestError<Value>) + Sync + Send {
+    /// Streams the items of every page of a paged query.
+    ///
+    /// Any `page` or `page_size` already set on `query` is overridden by `pagination`.
+    pub fn request_stream<'s, T, Q>(&'s self,query : Validated<Q>,pagination : Pagination) -> BoxStream<'s, T> where T : Send + 's, Q : PagedQuery<T> + 's {
+        self.send_stream(query.into_inner(),pagination)
+    }
+
+    /// Streams the items of every page of a paged query, validating the query for each page with [Query::build].
+    ///
+    /// A [ValidationError](crate::ValidationError) is passed to the error resolver and ends the stream, see [MusixAbgleich::send].
+    fn send_stream<'s, T, Q>(&'s self,query : Q,pagination : Pagination) -> BoxStream<'s, T> where T : Send + 's, Q : PagedQuery<T> + 's {
+        pagination.stream(move |page, page_size| self.send(query.clone().at_page(page,page_size)))
+    }
+
+    /// Streams the top artists by country. See [MusixAbgleich::top_artists_by_country].
+    pub fn top_artists_by_country_stream<'s>(&'s self,country : Option<Country>,pagination : Pagination) -> BoxStream<'s, Artist> {
+        let query = ChartArtistsQuery::new().country(country);
+        self.send_stream(query,pagination)
+    }
+
+    /// Streams the top tracks by country. See [MusixAbgleich::top_tracks_by_country].
+    pub fn top_tracks_by_country_stream<'s>(&'s self,country : Option<Country>,chart_name : Option<Chart>,has_lyrics : Option<bool>,pagination : Pagination) -> BoxStream<'s, Track> {
+        let query = ChartTracksQuery::new().country(country).chart_name(chart_name).has_lyrics(has_lyrics);
+        self.send_stream(query,pagination)
+    }
+
+    /// Streams the artists matching a search. See [MusixAbgleich::search_artist].
+    pub fn search_artist_stream<'s>(&'s self, artist_song: Option<&'s str>, artist_id: Option<ArtistId>, artist_mbid: Option<&'s Mbid>,pagination : Pagination) -> BoxStream<'s, Artist> {
+        let query = ArtistSearchQuery::new().artist_name(artist_song).filter_artist_id(artist_id).filter_artist_mbid(artist_mbid);
+        self.send_stream(query,pagination)
+    }
+
+    /// Streams the album discography of an artist. See [MusixAbgleich::artist_relating_albums].
+    pub fn artist_relating_albums_stream<'s>(&'s self,locator : impl Into<ArtistLocator>,album_name: Option<bool>,release_date_sort: Option<SortBy>,pagination : Pagination) -> BoxStream<'s, Album> {
+        let query = locator.into().albums_query();
+        let query = query.group_by_album_name(album_name).release_date_sort(release_date_sort);
+        self.send_stream(query,pagination)
+    }
+
+    /// Streams the artists related to a given one. See [MusixAbgleich::artist_relating_artist].
+    pub fn artist_relating_artist_stream<'s>(&'s self,locator : impl Into<ArtistLocator>,pagination : Pagination) -> BoxStream<'s, Artist> {
+        let query = locator.into().related_artists_query();
+        self.send_stream(query,pagination)
+    }
+
+    /// Streams the tracks of an album. See [MusixAbgleich::album_tracks].
+    pub fn album_tracks_stream<'s>(&'s self,locator : impl Into<AlbumLocator>,has_lyrics: Option<bool>,pagination : Pagination) -> BoxStream<'s, Track> {
+        let query = locator.into().tracks_query();
+        self.send_stream(query.has_lyrics(has_lyrics),pagination)
+    }
+
+    /// Streams the tracks matching a search. See [MusixAbgleich::search_track].
+    ///
+    /// Any `page` or `page_size` already set on `query` is overridden by `pagination`.
+    pub fn search_track_stream<'s>(&'s self,query : Validated<TrackSearchQuery>,pagination : Pagination) -> BoxStream<'s, Track> {
+        self.request_stream(query,pagination)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Mutex, atomic::{AtomicUsize, Ordering}};
+    use futures::executor::block_on;
+
+    /// Serves pages of `available` numbered items, recording every page and page size requested.
+    fn stub(available : u32,requests : &Mutex<Vec<(u32,u8)>>) -> impl FnMut(u32, u8) -> futures::future::Ready<Option<Page<u32>>> + Send + '_ {
+        move |page, page_size| {
+            requests.lock().unwrap().push((page,page_size));
+            let start = (page - 1) * u32::from(page_size);
+            let items = (start..available.min(start + u32::from(page_size))).collect();
+            futures::future::ready(Some(Page::new(items,page,u32::from(page_size),Some(available))))
+        }
+    }
+
+    #[test]
+    fn counts_pages() {
+        let page = Page::new(vec![1, 2, 3],1,3,Some(7));
+        assert!(page.has_next());
+        assert_eq!(page.total_pages(), Some(3));
+
+        let last = Page::new(vec![7],3,3,Some(7));
+        assert!(!last.has_next());
+        assert_eq!(last.total_pages(), Some(3));
+        assert_eq!(last.into_iter().collect::<Vec<_>>(), [7]);
+
+        let empty = Page::<u32>::new(vec![],1,10,Some(0));
+        assert!(!empty.has_next());
+        assert_eq!(empty.total_pages(), Some(0));
+        assert_eq!(empty.into_items(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn guesses_the_next_page_without_a_header() {
+        let full = Page::new(vec![1, 2],1,2,None);
+        assert!(full.has_next());
+        assert_eq!(full.total_pages(), None);
+
+        let partial = Page::new(vec![3],2,2,None);
+        assert!(!partial.has_next());
+        assert!(!Page::<u32>::new(vec![],1,0,None).has_next());
+        assert_eq!(Page::<u32>::new(vec![],1,0,Some(5)).total_pages(), None);
+    }
+
+    #[test]
+    fn streams_every_page() {
+        let requests = Mutex::new(Vec::new());
+        let items : Vec<u32> = block_on(Pagination::new().page_size(2).stream(stub(5,&requests)).collect());
+        assert_eq!(items, [0, 1, 2, 3, 4]);
+        assert_eq!(*requests.lock().unwrap(), [(1, 2), (2, 2), (3, 2)]);
+    }
+
+    #[test]
+    fn stops_at_max_items() {
+        let requests = Mutex::new(Vec::new());
+        let items : Vec<u32> = block_on(Pagination::new().page_size(2).max_items(3).stream(stub(10,&requests)).collect());
+        assert_eq!(items, [0, 1, 2]);
+        assert_eq!(*requests.lock().unwrap(), [(1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn clamps_page_size_and_starts_at_start_page() {
+        let requests = Mutex::new(Vec::new());
+        let items : Vec<u32> = block_on(Pagination::new().page_size(0).start_page(3).stream(stub(4,&requests)).collect());
+        assert_eq!(items, [2, 3]);
+        assert_eq!(*requests.lock().unwrap(), [(3, 1), (4, 1)]);
+
+        let requests = Mutex::new(Vec::new());
+        let _ = block_on(Pagination::new().page_size(200).start_page(0).stream(stub(150,&requests)).count());
+        assert_eq!(*requests.lock().unwrap(), [(1, 100), (2, 100)]);
+    }
+
+    #[test]
+    fn throttles_every_page_after_the_first() {
+        let throttled = Arc::new(AtomicUsize::new(0));
+        let counter = throttled.clone();
+        let pagination = Pagination::new().page_size(1).throttle(move || {
+            let _ = counter.fetch_add(1, Ordering::SeqCst);
+            futures::future::ready(())
+        });
+
+        let requests = Mutex::new(Vec::new());
+        let items : Vec<u32> = block_on(pagination.stream(stub(3,&requests)).collect());
+        assert_eq!(items, [0, 1, 2]);
+        assert_eq!(throttled.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn ends_on_a_failed_request() {
+        let mut served = 0;
+        let items : Vec<u32> = block_on(Pagination::new().page_size(1).stream(move |page, page_size| {
+            served += 1;
+            futures::future::ready((served < 2).then(|| Page::new(vec![page],page,u32::from(page_size),None)))
+        }).collect());
+        assert_eq!(items, [1]);
+    }
+}
@@ -0,0 +1,1226 @@
+#![allow(unused_results)]
+
+use std::collections::HashMap;
+use api_request_utils::serde_json::{Value, Error as JsonError};
+
+use crate::{
+    Chart,
+    Country,
+    Language,
+    SortBy,
+    SubtitleFormat,
+
+    Artist,
+    Track,
+    Lyrics,
+    LyricMood,
+    Snippet,
+    Genre,
+    Subtitle,
+    Album,
+    Page,
+
+    TrackId,
+    CommonTrackId,
+    ArtistId,
+    AlbumId,
+    Isrc,
+    Mbid,
+
+    Query,
+    PagedQuery,
+    ValidationError,
+
+    builder::{set, check_range, check_page, require_one, set_page, body_item, body_list, body_page},
+};
+
+/// A struct representing a query for the top artists of a country
+#[derive(Default, Clone, Debug)]
+pub struct ChartArtistsQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl ChartArtistsQuery {
+    /// Creates a new `ChartArtistsQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        ChartArtistsQuery(HashMap::new())
+    }
+
+    /// Set the country of the chart (default: [Country::UnitedStates]). Set [Country::Worldwide] for worldwide.
+    pub fn country(mut self, country: Option<Country>) -> Self {
+        static KEY : &str = "country";
+        set(&mut self.0, KEY, country);
+        self
+    }
+
+    /// Define the page number for paginated results.
+    pub fn page(mut self, page: Option<u32>) -> Self {
+        static KEY : &str = "page";
+        set(&mut self.0, KEY, page);
+        self
+    }
+
+    /// Define the page size for paginated results. Range is 1 to 100.
+    pub fn page_size(mut self, page_size: Option<u32>) -> Self {
+        static KEY : &str = "page_size";
+        set(&mut self.0, KEY, page_size);
+        self
+    }
+}
+
+impl Query for ChartArtistsQuery {
+    const ENDPOINT : &'static str = "chart.artists.get";
+    type Response = Page<Artist>;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        check_page(&self.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_page(message, &self.0, "artist_list", "artist")
+    }
+}
+
+impl PagedQuery<Artist> for ChartArtistsQuery {
+    fn at_page(mut self,page : u32,page_size : u8) -> Self {
+        set_page(&mut self.0, page, page_size);
+        self
+    }
+}
+
+/// A struct representing a query for the top tracks of a country
+#[derive(Default, Clone, Debug)]
+pub struct ChartTracksQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl ChartTracksQuery {
+    /// Creates a new `ChartTracksQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        ChartTracksQuery(HashMap::new())
+    }
+
+    /// Set the country of the chart (default: [Country::UnitedStates]). Set [Country::Worldwide] for worldwide.
+    pub fn country(mut self, country: Option<Country>) -> Self {
+        static KEY : &str = "country";
+        set(&mut self.0, KEY, country);
+        self
+    }
+
+    /// Select the chart to read from (default: the editorial chart).
+    pub fn chart_name(mut self, chart_name: Option<Chart>) -> Self {
+        static KEY : &str = "chart_name";
+        set(&mut self.0, KEY, chart_name);
+        self
+    }
+
+    /// Filter only contents with lyrics when set to true.
+    pub fn has_lyrics(mut self, f_has_lyrics: Option<bool>) -> Self {
+        static KEY : &str = "f_has_lyrics";
+        set(&mut self.0, KEY, f_has_lyrics);
+        self
+    }
+
+    /// Define the page number for paginated results.
+    pub fn page(mut self, page: Option<u32>) -> Self {
+        static KEY : &str = "page";
+        set(&mut self.0, KEY, page);
+        self
+    }
+
+    /// Define the page size for paginated results. Range is 1 to 100.
+    pub fn page_size(mut self, page_size: Option<u32>) -> Self {
+        static KEY : &str = "page_size";
+        set(&mut self.0, KEY, page_size);
+        self
+    }
+}
+
+impl Query for ChartTracksQuery {
+    const ENDPOINT : &'static str = "chart.tracks.get";
+    type Response = Page<Track>;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        check_page(&self.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_page(message, &self.0, "track_list", "track")
+    }
+}
+
+impl PagedQuery<Track> for ChartTracksQuery {
+    fn at_page(mut self,page : u32,page_size : u8) -> Self {
+        set_page(&mut self.0, page, page_size);
+        self
+    }
+}
+
+/// A struct representing a query for matching a song against the database
+#[derive(Default, Clone, Debug)]
+pub struct TrackMatchQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl TrackMatchQuery {
+    /// Creates a new `TrackMatchQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        TrackMatchQuery(HashMap::new())
+    }
+
+    /// Set the song title.
+    pub fn title(mut self, q_track: Option<&str>) -> Self {
+        static KEY : &str = "q_track";
+        set(&mut self.0, KEY, q_track);
+        self
+    }
+
+    /// Set the song artist.
+    pub fn artist(mut self, q_artist: Option<&str>) -> Self {
+        static KEY : &str = "q_artist";
+        set(&mut self.0, KEY, q_artist);
+        self
+    }
+
+    /// Set the song album.
+    pub fn album(mut self, q_album: Option<&str>) -> Self {
+        static KEY : &str = "q_album";
+        set(&mut self.0, KEY, q_album);
+        self
+    }
+}
+
+impl Query for TrackMatchQuery {
+    const ENDPOINT : &'static str = "matcher.track.get";
+    type Response = Track;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        Ok(())
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("track"))
+    }
+}
+
+/// A struct representing a query for a track by its identifier
+#[derive(Default, Clone, Debug)]
+pub struct TrackQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl TrackQuery {
+    /// Creates a new `TrackQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        TrackQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch track id.
+    pub fn track_id(mut self, track_id: Option<TrackId>) -> Self {
+        static KEY : &str = "track_id";
+        set(&mut self.0, KEY, track_id);
+        self
+    }
+
+    /// Set the Musixmatch commontrack id.
+    pub fn commontrack_id(mut self, commontrack_id: Option<CommonTrackId>) -> Self {
+        static KEY : &str = "commontrack_id";
+        set(&mut self.0, KEY, commontrack_id);
+        self
+    }
+
+    /// Set a valid ISRC identifier.
+    pub fn track_isrc(mut self, track_isrc: Option<&Isrc>) -> Self {
+        static KEY : &str = "track_isrc";
+        set(&mut self.0, KEY, track_isrc);
+        self
+    }
+
+    /// Set the MusicBrainz recording id.
+    pub fn track_mbid(mut self, track_mbid: Option<&Mbid>) -> Self {
+        static KEY : &str = "track_mbid";
+        set(&mut self.0, KEY, track_mbid);
+        self
+    }
+}
+
+impl Query for TrackQuery {
+    const ENDPOINT : &'static str = "track.get";
+    type Response = Track;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["track_id", "commontrack_id", "track_isrc", "track_mbid"])
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("track"))
+    }
+}
+
+/// A struct representing a query for matching the lyrics of a song
+#[derive(Default, Clone, Debug)]
+pub struct LyricsMatchQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl LyricsMatchQuery {
+    /// Creates a new `LyricsMatchQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        LyricsMatchQuery(HashMap::new())
+    }
+
+    /// Set the song title.
+    pub fn title(mut self, q_track: Option<&str>) -> Self {
+        static KEY : &str = "q_track";
+        set(&mut self.0, KEY, q_track);
+        self
+    }
+
+    /// Set the song artist.
+    pub fn artist(mut self, q_artist: Option<&str>) -> Self {
+        static KEY : &str = "q_artist";
+        set(&mut self.0, KEY, q_artist);
+        self
+    }
+
+    /// Set a valid ISRC identifier.
+    pub fn track_isrc(mut self, track_isrc: Option<&Isrc>) -> Self {
+        static KEY : &str = "track_isrc";
+        set(&mut self.0, KEY, track_isrc);
+        self
+    }
+}
+
+impl Query for LyricsMatchQuery {
+    const ENDPOINT : &'static str = "matcher.lyrics.get";
+    type Response = Lyrics;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        Ok(())
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("lyrics"))
+    }
+}
+
+/// A struct representing a query for the lyrics of a track
+#[derive(Default, Clone, Debug)]
+pub struct LyricsQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl LyricsQuery {
+    /// Creates a new `LyricsQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        LyricsQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch commontrack id.
+    pub fn commontrack_id(mut self, commontrack_id: Option<CommonTrackId>) -> Self {
+        static KEY : &str = "commontrack_id";
+        set(&mut self.0, KEY, commontrack_id);
+        self
+    }
+
+    /// Set the Musixmatch track id.
+    pub fn track_id(mut self, track_id: Option<TrackId>) -> Self {
+        static KEY : &str = "track_id";
+        set(&mut self.0, KEY, track_id);
+        self
+    }
+}
+
+impl Query for LyricsQuery {
+    const ENDPOINT : &'static str = "track.lyrics.get";
+    type Response = Lyrics;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["commontrack_id", "track_id"])
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("lyrics"))
+    }
+}
+
+/// A struct representing a query for the translated lyrics of a track
+#[derive(Default, Clone, Debug)]
+pub struct TranslationQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl TranslationQuery {
+    /// Creates a new `TranslationQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        TranslationQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch commontrack id.
+    pub fn commontrack_id(mut self, commontrack_id: Option<CommonTrackId>) -> Self {
+        static KEY : &str = "commontrack_id";
+        set(&mut self.0, KEY, commontrack_id);
+        self
+    }
+
+    /// Set the Musixmatch track id.
+    pub fn track_id(mut self, track_id: Option<TrackId>) -> Self {
+        static KEY : &str = "track_id";
+        set(&mut self.0, KEY, track_id);
+        self
+    }
+
+    /// Set a valid ISRC identifier.
+    pub fn track_isrc(mut self, track_isrc: Option<&Isrc>) -> Self {
+        static KEY : &str = "track_isrc";
+        set(&mut self.0, KEY, track_isrc);
+        self
+    }
+
+    /// Set the MusicBrainz recording id.
+    pub fn track_mbid(mut self, track_mbid: Option<&Mbid>) -> Self {
+        static KEY : &str = "track_mbid";
+        set(&mut self.0, KEY, track_mbid);
+        self
+    }
+
+    /// Set the language of the translation (ISO 639-1).
+    pub fn selected_language(mut self, selected_language: Option<&Language>) -> Self {
+        static KEY : &str = "selected_language";
+        set(&mut self.0, KEY, selected_language);
+        self
+    }
+
+    /// Only return translations completed over this ratio, from 0 to 1. Set it to 1 for completed translations only, or to 0.7 for a minimum of 70% complete translations.
+    pub fn min_completed(mut self, min_completed: Option<f32>) -> Self {
+        static KEY : &str = "min_completed";
+        set(&mut self.0, KEY, min_completed.map(|min_completed| min_completed.to_string()));
+        self
+    }
+}
+
+impl Query for TranslationQuery {
+    const ENDPOINT : &'static str = "track.lyrics.translation.get";
+    type Response = Lyrics;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["commontrack_id", "track_id", "track_isrc", "track_mbid"])?;
+        check_range(&self.0, "min_completed", 0.0, 1.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("lyrics"))
+    }
+}
+
+/// A struct representing a query for the mood of the lyrics of a track
+#[derive(Default, Clone, Debug)]
+pub struct MoodQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl MoodQuery {
+    /// Creates a new `MoodQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        MoodQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch commontrack id.
+    pub fn commontrack_id(mut self, commontrack_id: Option<CommonTrackId>) -> Self {
+        static KEY : &str = "commontrack_id";
+        set(&mut self.0, KEY, commontrack_id);
+        self
+    }
+
+    /// Set a valid ISRC identifier.
+    pub fn track_isrc(mut self, track_isrc: Option<&Isrc>) -> Self {
+        static KEY : &str = "track_isrc";
+        set(&mut self.0, KEY, track_isrc);
+        self
+    }
+}
+
+impl Query for MoodQuery {
+    const ENDPOINT : &'static str = "track.lyrics.mood.get";
+    type Response = LyricMood;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["commontrack_id", "track_isrc"])
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, None)
+    }
+}
+
+/// A struct representing a query for the lyrics snippet of a track
+#[derive(Clone, Debug)]
+pub struct SnippetQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl SnippetQuery {
+    /// Creates a new `SnippetQuery`.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_id` - The Musixmatch track id.
+    pub fn new(track_id : TrackId) -> Self {
+        SnippetQuery(HashMap::from([("track_id", Value::from(track_id))]))
+    }
+}
+
+impl Query for SnippetQuery {
+    const ENDPOINT : &'static str = "track.snippet.get";
+    type Response = Snippet;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        Ok(())
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("snippet"))
+    }
+}
+
+/// A struct representing a query for the subtitle of a track
+#[derive(Clone, Debug)]
+pub struct SubtitleQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl SubtitleQuery {
+    /// Creates a new `SubtitleQuery`.
+    ///
+    /// # Arguments
+    ///
+    /// * `commontrack_id` - The Musixmatch commontrack id.
+    pub fn new(commontrack_id : CommonTrackId) -> Self {
+        SubtitleQuery(HashMap::from([("commontrack_id", Value::from(commontrack_id))]))
+    }
+
+    /// Filter by subtitle length in seconds.
+    pub fn subtitle_length(mut self, f_subtitle_length: Option<u16>) -> Self {
+        static KEY : &str = "f_subtitle_length";
+        set(&mut self.0, KEY, f_subtitle_length);
+        self
+    }
+
+    /// Set the maximum deviation allowed from the subtitle length in seconds.
+    pub fn max_deviation(mut self, f_subtitle_length_max_deviation: Option<u8>) -> Self {
+        static KEY : &str = "f_subtitle_length_max_deviation";
+        set(&mut self.0, KEY, f_subtitle_length_max_deviation);
+        self
+    }
+
+    /// Set the format of the subtitle (LRC, DFXP, STLEDU, MXM). Defaults to LRC.
+    pub fn format(mut self, subtitle_format: Option<SubtitleFormat>) -> Self {
+        static KEY : &str = "subtitle_format";
+        set(&mut self.0, KEY, subtitle_format);
+        self
+    }
+}
+
+impl Query for SubtitleQuery {
+    const ENDPOINT : &'static str = "track.subtitle.get";
+    type Response = Subtitle;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        Ok(())
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("subtitle"))
+    }
+}
+
+/// A struct representing a query for matching the subtitle of a song
+#[derive(Default, Clone, Debug)]
+pub struct SubtitleMatchQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl SubtitleMatchQuery {
+    /// Creates a new `SubtitleMatchQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        SubtitleMatchQuery(HashMap::new())
+    }
+
+    /// Set the song title.
+    pub fn title(mut self, q_track: Option<&str>) -> Self {
+        static KEY : &str = "q_track";
+        set(&mut self.0, KEY, q_track);
+        self
+    }
+
+    /// Set the song artist.
+    pub fn artist(mut self, q_artist: Option<&str>) -> Self {
+        static KEY : &str = "q_artist";
+        set(&mut self.0, KEY, q_artist);
+        self
+    }
+
+    /// Set the song album.
+    pub fn album(mut self, q_album: Option<&str>) -> Self {
+        static KEY : &str = "q_album";
+        set(&mut self.0, KEY, q_album);
+        self
+    }
+
+    /// Filter by subtitle length in seconds.
+    pub fn subtitle_length(mut self, f_subtitle_length: Option<u16>) -> Self {
+        static KEY : &str = "f_subtitle_length";
+        set(&mut self.0, KEY, f_subtitle_length);
+        self
+    }
+
+    /// Set the maximum deviation allowed from the subtitle length in seconds.
+    pub fn max_deviation(mut self, f_subtitle_length_max_deviation: Option<u8>) -> Self {
+        static KEY : &str = "f_subtitle_length_max_deviation";
+        set(&mut self.0, KEY, f_subtitle_length_max_deviation);
+        self
+    }
+
+    /// Set the format of the subtitle (LRC, DFXP, STLEDU, MXM). Defaults to LRC.
+    pub fn format(mut self, subtitle_format: Option<SubtitleFormat>) -> Self {
+        static KEY : &str = "subtitle_format";
+        set(&mut self.0, KEY, subtitle_format);
+        self
+    }
+}
+
+impl Query for SubtitleMatchQuery {
+    const ENDPOINT : &'static str = "matcher.subtitle.get";
+    type Response = Subtitle;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        Ok(())
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("subtitle"))
+    }
+}
+
+/// A struct representing a query for the translated subtitle of a track
+#[derive(Default, Clone, Debug)]
+pub struct SubtitleTranslationQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl SubtitleTranslationQuery {
+    /// Creates a new `SubtitleTranslationQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        SubtitleTranslationQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch commontrack id.
+    pub fn commontrack_id(mut self, commontrack_id: Option<CommonTrackId>) -> Self {
+        static KEY : &str = "commontrack_id";
+        set(&mut self.0, KEY, commontrack_id);
+        self
+    }
+
+    /// Set a valid ISRC identifier.
+    pub fn track_isrc(mut self, track_isrc: Option<&Isrc>) -> Self {
+        static KEY : &str = "track_isrc";
+        set(&mut self.0, KEY, track_isrc);
+        self
+    }
+
+    /// Set the language of the translation (ISO 639-1).
+    pub fn selected_language(mut self, selected_language: Option<&Language>) -> Self {
+        static KEY : &str = "selected_language";
+        set(&mut self.0, KEY, selected_language);
+        self
+    }
+
+    /// Only return translations completed over this ratio, from 0 to 1. Set it to 1 for completed translations only, or to 0.7 for a minimum of 70% complete translations.
+    pub fn min_completed(mut self, min_completed: Option<f32>) -> Self {
+        static KEY : &str = "min_completed";
+        set(&mut self.0, KEY, min_completed.map(|min_completed| min_completed.to_string()));
+        self
+    }
+
+    /// Filter by subtitle length in seconds.
+    pub fn subtitle_length(mut self, f_subtitle_length: Option<u16>) -> Self {
+        static KEY : &str = "f_subtitle_length";
+        set(&mut self.0, KEY, f_subtitle_length);
+        self
+    }
+
+    /// Set the maximum deviation allowed from the subtitle length in seconds.
+    pub fn max_deviation(mut self, f_subtitle_length_max_deviation: Option<u8>) -> Self {
+        static KEY : &str = "f_subtitle_length_max_deviation";
+        set(&mut self.0, KEY, f_subtitle_length_max_deviation);
+        self
+    }
+}
+
+impl Query for SubtitleTranslationQuery {
+    const ENDPOINT : &'static str = "track.subtitle.translation.get";
+    type Response = Subtitle;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["commontrack_id", "track_isrc"])?;
+        check_range(&self.0, "min_completed", 0.0, 1.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("subtitle_translated"))
+    }
+}
+
+/// A struct representing a query for searching artists
+#[derive(Default, Clone, Debug)]
+pub struct ArtistSearchQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl ArtistSearchQuery {
+    /// Creates a new `ArtistSearchQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        ArtistSearchQuery(HashMap::new())
+    }
+
+    /// Set the artist name to search for.
+    pub fn artist_name(mut self, q_artist: Option<&str>) -> Self {
+        static KEY : &str = "q_artist";
+        set(&mut self.0, KEY, q_artist);
+        self
+    }
+
+    /// Filter by this artist id.
+    pub fn filter_artist_id(mut self, f_artist_id: Option<ArtistId>) -> Self {
+        static KEY : &str = "f_artist_id";
+        set(&mut self.0, KEY, f_artist_id);
+        self
+    }
+
+    /// Filter by this MusicBrainz artist id.
+    pub fn filter_artist_mbid(mut self, f_artist_mbid: Option<&Mbid>) -> Self {
+        static KEY : &str = "f_artist_mbid";
+        set(&mut self.0, KEY, f_artist_mbid);
+        self
+    }
+
+    /// Define the page number for paginated results.
+    pub fn page(mut self, page: Option<u32>) -> Self {
+        static KEY : &str = "page";
+        set(&mut self.0, KEY, page);
+        self
+    }
+
+    /// Define the page size for paginated results. Range is 1 to 100.
+    pub fn page_size(mut self, page_size: Option<u32>) -> Self {
+        static KEY : &str = "page_size";
+        set(&mut self.0, KEY, page_size);
+        self
+    }
+}
+
+impl Query for ArtistSearchQuery {
+    const ENDPOINT : &'static str = "artist.search";
+    type Response = Page<Artist>;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        check_page(&self.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_page(message, &self.0, "artist_list", "artist")
+    }
+}
+
+impl PagedQuery<Artist> for ArtistSearchQuery {
+    fn at_page(mut self,page : u32,page_size : u8) -> Self {
+        set_page(&mut self.0, page, page_size);
+        self
+    }
+}
+
+/// A struct representing a query for an artist by its identifier
+#[derive(Default, Clone, Debug)]
+pub struct ArtistQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl ArtistQuery {
+    /// Creates a new `ArtistQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        ArtistQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch artist id.
+    pub fn artist_id(mut self, artist_id: Option<ArtistId>) -> Self {
+        static KEY : &str = "artist_id";
+        set(&mut self.0, KEY, artist_id);
+        self
+    }
+
+    /// Set the MusicBrainz artist id.
+    pub fn artist_mbid(mut self, artist_mbid: Option<&Mbid>) -> Self {
+        static KEY : &str = "artist_mbid";
+        set(&mut self.0, KEY, artist_mbid);
+        self
+    }
+}
+
+impl Query for ArtistQuery {
+    const ENDPOINT : &'static str = "artist.get";
+    type Response = Artist;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["artist_id", "artist_mbid"])
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("artist"))
+    }
+}
+
+/// A struct representing a query for the album discography of an artist
+#[derive(Default, Clone, Debug)]
+pub struct ArtistAlbumsQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl ArtistAlbumsQuery {
+    /// Creates a new `ArtistAlbumsQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        ArtistAlbumsQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch artist id.
+    pub fn artist_id(mut self, artist_id: Option<ArtistId>) -> Self {
+        static KEY : &str = "artist_id";
+        set(&mut self.0, KEY, artist_id);
+        self
+    }
+
+    /// Set the MusicBrainz artist id.
+    pub fn artist_mbid(mut self, artist_mbid: Option<&Mbid>) -> Self {
+        static KEY : &str = "artist_mbid";
+        set(&mut self.0, KEY, artist_mbid);
+        self
+    }
+
+    /// Group albums sharing the same name.
+    pub fn group_by_album_name(mut self, g_album_name: Option<bool>) -> Self {
+        static KEY : &str = "g_album_name";
+        set(&mut self.0, KEY, g_album_name);
+        self
+    }
+
+    /// Sort by release date (asc|desc).
+    pub fn release_date_sort(mut self, s_release_date: Option<SortBy>) -> Self {
+        static KEY : &str = "s_release_date";
+        set(&mut self.0, KEY, s_release_date);
+        self
+    }
+
+    /// Define the page number for paginated results.
+    pub fn page(mut self, page: Option<u32>) -> Self {
+        static KEY : &str = "page";
+        set(&mut self.0, KEY, page);
+        self
+    }
+
+    /// Define the page size for paginated results. Range is 1 to 100.
+    pub fn page_size(mut self, page_size: Option<u32>) -> Self {
+        static KEY : &str = "page_size";
+        set(&mut self.0, KEY, page_size);
+        self
+    }
+}
+
+impl Query for ArtistAlbumsQuery {
+    const ENDPOINT : &'static str = "artist.albums.get";
+    type Response = Page<Album>;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["artist_id", "artist_mbid"])?;
+        check_page(&self.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_page(message, &self.0, "album_list", "album")
+    }
+}
+
+impl PagedQuery<Album> for ArtistAlbumsQuery {
+    fn at_page(mut self,page : u32,page_size : u8) -> Self {
+        set_page(&mut self.0, page, page_size);
+        self
+    }
+}
+
+/// A struct representing a query for the artists related to a given one
+#[derive(Default, Clone, Debug)]
+pub struct RelatedArtistsQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl RelatedArtistsQuery {
+    /// Creates a new `RelatedArtistsQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        RelatedArtistsQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch artist id.
+    pub fn artist_id(mut self, artist_id: Option<ArtistId>) -> Self {
+        static KEY : &str = "artist_id";
+        set(&mut self.0, KEY, artist_id);
+        self
+    }
+
+    /// Set the MusicBrainz artist id.
+    pub fn artist_mbid(mut self, artist_mbid: Option<&Mbid>) -> Self {
+        static KEY : &str = "artist_mbid";
+        set(&mut self.0, KEY, artist_mbid);
+        self
+    }
+
+    /// Define the page number for paginated results.
+    pub fn page(mut self, page: Option<u32>) -> Self {
+        static KEY : &str = "page";
+        set(&mut self.0, KEY, page);
+        self
+    }
+
+    /// Define the page size for paginated results. Range is 1 to 100.
+    pub fn page_size(mut self, page_size: Option<u32>) -> Self {
+        static KEY : &str = "page_size";
+        set(&mut self.0, KEY, page_size);
+        self
+    }
+}
+
+impl Query for RelatedArtistsQuery {
+    const ENDPOINT : &'static str = "artist.related.get";
+    type Response = Page<Artist>;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["artist_id", "artist_mbid"])?;
+        check_page(&self.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_page(message, &self.0, "artist_list", "artist")
+    }
+}
+
+impl PagedQuery<Artist> for RelatedArtistsQuery {
+    fn at_page(mut self,page : u32,page_size : u8) -> Self {
+        set_page(&mut self.0, page, page_size);
+        self
+    }
+}
+
+/// A struct representing a query for an album by its identifier
+#[derive(Clone, Debug)]
+pub struct AlbumQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl AlbumQuery {
+    /// Creates a new `AlbumQuery`.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The Musixmatch album id.
+    pub fn new(album_id : AlbumId) -> Self {
+        AlbumQuery(HashMap::from([("album_id", Value::from(album_id))]))
+    }
+}
+
+impl Query for AlbumQuery {
+    const ENDPOINT : &'static str = "album.get";
+    type Response = Album;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        Ok(())
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("album"))
+    }
+}
+
+/// A struct representing a query for the tracks of an album
+#[derive(Default, Clone, Debug)]
+pub struct AlbumTracksQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl AlbumTracksQuery {
+    /// Creates a new `AlbumTracksQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        AlbumTracksQuery(HashMap::new())
+    }
+
+    /// Set the Musixmatch album id.
+    pub fn album_id(mut self, album_id: Option<AlbumId>) -> Self {
+        static KEY : &str = "album_id";
+        set(&mut self.0, KEY, album_id);
+        self
+    }
+
+    /// Set the MusicBrainz album id.
+    pub fn album_mbid(mut self, album_mbid: Option<&Mbid>) -> Self {
+        static KEY : &str = "album_mbid";
+        set(&mut self.0, KEY, album_mbid);
+        self
+    }
+
+    /// Filter only contents with lyrics when set to true.
+    pub fn has_lyrics(mut self, f_has_lyrics: Option<bool>) -> Self {
+        static KEY : &str = "f_has_lyrics";
+        set(&mut self.0, KEY, f_has_lyrics);
+        self
+    }
+
+    /// Define the page number for paginated results.
+    pub fn page(mut self, page: Option<u32>) -> Self {
+        static KEY : &str = "page";
+        set(&mut self.0, KEY, page);
+        self
+    }
+
+    /// Define the page size for paginated results. Range is 1 to 100.
+    pub fn page_size(mut self, page_size: Option<u32>) -> Self {
+        static KEY : &str = "page_size";
+        set(&mut self.0, KEY, page_size);
+        self
+    }
+}
+
+impl Query for AlbumTracksQuery {
+    const ENDPOINT : &'static str = "album.tracks.get";
+    type Response = Page<Track>;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        require_one(&self.0, &["album_id", "album_mbid"])?;
+        check_page(&self.0)
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_page(message, &self.0, "track_list", "track")
+    }
+}
+
+impl PagedQuery<Track> for AlbumTracksQuery {
+    fn at_page(mut self,page : u32,page_size : u8) -> Self {
+        set_page(&mut self.0, page, page_size);
+        self
+    }
+}
+
+/// A struct representing a query for the music genres of the catalogue
+#[derive(Default, Clone, Debug)]
+pub struct GenresQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl GenresQuery {
+    /// Creates a new `GenresQuery` instance with an empty HashMap.
+    pub fn new() -> Self {
+        GenresQuery(HashMap::new())
+    }
+}
+
+impl Query for GenresQuery {
+    const ENDPOINT : &'static str = "music.genres.get";
+    type Response = Vec<Genre>;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        Ok(())
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_list(message, "music_genre_list", "music_genre")
+    }
+}
+
+/// A struct representing a query for the base url of the tracking script
+#[derive(Clone, Debug)]
+pub struct TrackingUrlQuery(pub(crate) HashMap<&'static str,Value>);
+
+impl TrackingUrlQuery {
+    /// Creates a new `TrackingUrlQuery`.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - Your domain name.
+    pub fn new(domain : &str) -> Self {
+        TrackingUrlQuery(HashMap::from([("domain", Value::from(domain))]))
+    }
+}
+
+impl Query for TrackingUrlQuery {
+    const ENDPOINT : &'static str = "tracking.url.get";
+    type Response = String;
+
+    fn validate(&self) -> Result<(),ValidationError> {
+        Ok(())
+    }
+
+    fn parameters(&self) -> &HashMap<&'static str,Value> {
+        &self.0
+    }
+
+    fn response(&self,message : &Value) -> Result<Self::Response,JsonError> {
+        body_item(message, Some("url"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::*;
+
+    #[test]
+    fn queries_point_at_their_endpoints() {
+        assert_eq!(ChartArtistsQuery::ENDPOINT, "chart.artists.get");
+        assert_eq!(ChartTracksQuery::ENDPOINT, "chart.tracks.get");
+        assert_eq!(TrackMatchQuery::ENDPOINT, "matcher.track.get");
+        assert_eq!(TrackQuery::ENDPOINT, "track.get");
+        assert_eq!(LyricsMatchQuery::ENDPOINT, "matcher.lyrics.get");
+        assert_eq!(LyricsQuery::ENDPOINT, "track.lyrics.get");
+        assert_eq!(TranslationQuery::ENDPOINT, "track.lyrics.translation.get");
+        assert_eq!(MoodQuery::ENDPOINT, "track.lyrics.mood.get");
+        assert_eq!(SnippetQuery::ENDPOINT, "track.snippet.get");
+        assert_eq!(SubtitleQuery::ENDPOINT, "track.subtitle.get");
+        assert_eq!(SubtitleMatchQuery::ENDPOINT, "matcher.subtitle.get");
+        assert_eq!(SubtitleTranslationQuery::ENDPOINT, "track.subtitle.translation.get");
+        assert_eq!(ArtistSearchQuery::ENDPOINT, "artist.search");
+        assert_eq!(ArtistQuery::ENDPOINT, "artist.get");
+        assert_eq!(ArtistAlbumsQuery::ENDPOINT, "artist.albums.get");
+        assert_eq!(RelatedArtistsQuery::ENDPOINT, "artist.related.get");
+        assert_eq!(AlbumQuery::ENDPOINT, "album.get");
+        assert_eq!(AlbumTracksQuery::ENDPOINT, "album.tracks.get");
+        assert_eq!(GenresQuery::ENDPOINT, "music.genres.get");
+        assert_eq!(TrackingUrlQuery::ENDPOINT, "tracking.url.get");
+    }
+
+    #[test]
+    fn chart_queries() {
+        assert_eq!(query_string(ChartArtistsQuery::new()), "");
+        assert_eq!(query_string(ChartArtistsQuery::new().country(Some(Country::UnitedStates)).page(Some(2)).page_size(Some(50))), "country=US&page=2&page_size=50");
+        assert_eq!(query_string(ChartTracksQuery::new().chart_name(Some(Chart::HotMostViewedLyricsLast2Hours)).has_lyrics(Some(true))), "chart_name=hot&f_has_lyrics=true");
+        assert_eq!(out_of_range(ChartArtistsQuery::new().page(Some(0))), "page");
+        assert_eq!(out_of_range(ChartTracksQuery::new().page_size(Some(101))), "page_size");
+    }
+
+    #[test]
+    fn track_queries() {
+        assert_eq!(query_string(TrackMatchQuery::new().title(Some("One More Time")).artist(Some("Daft Punk")).album(Some("Discovery"))), "q_album=Discovery&q_artist=Daft+Punk&q_track=One+More+Time");
+        assert_eq!(query_string(TrackQuery::new().track_id(Some(TrackId(1)))), "track_id=1");
+        assert_eq!(query_string(TrackQuery::new().commontrack_id(Some(CommonTrackId(2)))), "commontrack_id=2");
+        assert_eq!(query_string(TrackQuery::new().track_isrc(Some(&isrc()))), "track_isrc=USVI20400001");
+        assert_eq!(query_string(TrackQuery::new().track_mbid(Some(&mbid()))), "track_mbid=056e4f3e-d505-4dad-8ec1-d04f521cbb56");
+        assert_eq!(missing(TrackQuery::new()), ["track_id", "commontrack_id", "track_isrc", "track_mbid"]);
+        assert_eq!(query_string(SnippetQuery::new(TrackId(3))), "track_id=3");
+    }
+
+    #[test]
+    fn lyrics_queries() {
+        assert_eq!(query_string(LyricsMatchQuery::new().title(Some("Aerodynamic")).artist(Some("Daft Punk"))), "q_artist=Daft+Punk&q_track=Aerodynamic");
+        assert_eq!(query_string(LyricsMatchQuery::new().track_isrc(Some(&isrc()))), "track_isrc=USVI20400001");
+        assert_eq!(query_string(LyricsQuery::new().commontrack_id(Some(CommonTrackId(2)))), "commontrack_id=2");
+        assert_eq!(missing(LyricsQuery::new()), ["commontrack_id", "track_id"]);
+
+        assert_eq!(query_string(TranslationQuery::new().track_mbid(Some(&mbid())).selected_language(Some(&Language::French)).min_completed(Some(0.7))), "min_completed=0.7&selected_language=fr&track_mbid=056e4f3e-d505-4dad-8ec1-d04f521cbb56");
+        assert_eq!(missing(TranslationQuery::new().min_completed(Some(1.0))), ["commontrack_id", "track_id", "track_isrc", "track_mbid"]);
+        assert_eq!(out_of_range(TranslationQuery::new().track_id(Some(TrackId(1))).min_completed(Some(1.5))), "min_completed");
+        assert_eq!(out_of_range(TranslationQuery::new().track_id(Some(TrackId(1))).min_completed(Some(-0.1))), "min_completed");
+
+        assert_eq!(query_string(MoodQuery::new().track_isrc(Some(&isrc()))), "track_isrc=USVI20400001");
+        assert_eq!(missing(MoodQuery::new()), ["commontrack_id", "track_isrc"]);
+    }
+
+    #[test]
+    fn subtitle_queries() {
+        let query = SubtitleQuery::new(CommonTrackId(5)).subtitle_length(Some(200)).max_deviation(Some(3)).format(Some(SubtitleFormat::Mxm));
+        assert_eq!(query_string(query), "commontrack_id=5&f_subtitle_length=200&f_subtitle_length_max_deviation=3&subtitle_format=mxm");
+        assert_eq!(query_string(SubtitleMatchQuery::new().title(Some("Digital Love")).album(Some("Discovery")).format(Some(SubtitleFormat::Dfxp))), "q_album=Discovery&q_track=Digital+Love&subtitle_format=dfxp");
+
+        let query = SubtitleTranslationQuery::new().track_isrc(Some(&isrc())).selected_language(Some(&Language::German)).min_completed(Some(1.0)).subtitle_length(Some(200));
+        assert_eq!(query_string(query), "f_subtitle_length=200&min_completed=1&selected_language=de&track_isrc=USVI20400001");
+        assert_eq!(missing(SubtitleTranslationQuery::new()), ["commontrack_id", "track_isrc"]);
+        assert_eq!(out_of_range(SubtitleTranslationQuery::new().commontrack_id(Some(CommonTrackId(5))).min_completed(Some(2.0))), "min_completed");
+    }
+
+    #[test]
+    fn artist_queries() {
+        let query = ArtistSearchQuery::new().artist_name(Some("Daft Punk")).filter_artist_id(Some(ArtistId(118))).filter_artist_mbid(Some(&mbid()));
+        assert_eq!(query_string(query), "f_artist_id=118&f_artist_mbid=056e4f3e-d505-4dad-8ec1-d04f521cbb56&q_artist=Daft+Punk");
+        assert_eq!(out_of_range(ArtistSearchQuery::new().page_size(Some(0))), "page_size");
+
+        assert_eq!(query_string(ArtistQuery::new().artist_mbid(Some(&mbid()))), "artist_mbid=056e4f3e-d505-4dad-8ec1-d04f521cbb56");
+        assert_eq!(missing(ArtistQuery::new()), ["artist_id", "artist_mbid"]);
+
+        let query = ArtistAlbumsQuery::new().artist_id(Some(ArtistId(118))).group_by_album_name(Some(true)).release_date_sort(Some(SortBy::Desecending)).page(Some(1));
+        assert_eq!(query_string(query), "artist_id=118&g_album_name=true&page=1&s_release_date=desc");
+        assert_eq!(missing(ArtistAlbumsQuery::new().page(Some(1))), ["artist_id", "artist_mbid"]);
+        assert_eq!(out_of_range(ArtistAlbumsQuery::new().artist_id(Some(ArtistId(118))).page_size(Some(101))), "page_size");
+
+        assert_eq!(query_string(RelatedArtistsQuery::new().artist_id(Some(ArtistId(118))).page_size(Some(10))), "artist_id=118&page_size=10");
+        assert_eq!(missing(RelatedArtistsQuery::new()), ["artist_id", "artist_mbid"]);
+    }
+
+    #[test]
+    fn album_and_other_queries() {
+        assert_eq!(query_string(AlbumQuery::new(AlbumId(9))), "album_id=9");
+        assert_eq!(query_string(AlbumTracksQuery::new().album_mbid(Some(&mbid())).has_lyrics(Some(false))), "album_mbid=056e4f3e-d505-4dad-8ec1-d04f521cbb56&f_has_lyrics=false");
+        assert_eq!(missing(AlbumTracksQuery::new()), ["album_id", "album_mbid"]);
+        assert_eq!(out_of_range(AlbumTracksQuery::new().album_id(Some(AlbumId(9))).page(Some(0))), "page");
+
+        assert_eq!(query_string(GenresQuery::new()), "");
+        assert_eq!(query_string(TrackingUrlQuery::new("example.com")), "domain=example.com");
+    }
+
+    #[test]
+    fn at_page_overrides_the_page() {
+        let query = AlbumTracksQuery::new().album_id(Some(AlbumId(9))).page(Some(4)).page_size(Some(20)).at_page(2,50);
+        assert_eq!(query_string(query), "album_id=9&page=2&page_size=50");
+    }
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use api_request_utils::serde::Deserialize;
+use api_request_utils::serde_json;
+
+use crate::{SyncedLine, SyncedLyrics, SyncedLyricsError, SyncedWord};
+
+/// A line of a richsync body.
+#[derive(Deserialize)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+struct RichsyncLine {
+    /// The start of the line, in seconds.
+    ts : f64,
+    /// The end of the line, in seconds.
+    te : f64,
+    /// The words and the whitespace between them.
+    l : Vec<RichsyncChunk>,
+    /// The text of the line.
+    x : String,
+}
+
+/// A word or the whitespace between words, starting `o` seconds after its line.
+#[derive(Deserialize)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+struct RichsyncChunk {
+    c : String,
+    o : f64,
+}
+
+/// Converts seconds to a duration rounded to the millisecond.
+fn seconds(seconds : f64) -> Result<Duration,SyncedLyricsError> {
+    let millis = (seconds * 1000.0).round();
+    match millis.is_finite() && millis >= 0.0 && millis < u64::MAX as f64 {
+        true => Ok(Duration::from_millis(millis as u64)),
+        false => Err(SyncedLyricsError::InvalidTime(seconds.to_string())),
+    }
+}
+
+impl SyncedLyrics {
+    /// Parses a richsync body, the word-timed lyrics of a track, into lines with their words and end times.
+    ///
+    /// Richsync bodies are JSON such as `[{"ts":12.5,"te":13.3,"l":[{"c":"Work","o":0},{"c":" ","o":0.4},{"c":"it","o":0.5}],"x":"Work it"}]`,
+    /// where every word starts `o` seconds after its line.
+    pub fn from_richsync(body : &str) -> Result<Self,SyncedLyricsError> {
+        let lines : Vec<RichsyncLine> = serde_json::from_str(body).map_err(|error| SyncedLyricsError::InvalidRichsync(error.to_string()))?;
+        let lines = lines.into_iter()
+            .map(|line| {
+                let words = line.l.iter()
+                    .filter(|chunk| !chunk.c.trim().is_empty())
+                    .map(|chunk| Ok(SyncedWord::new(seconds(line.ts + chunk.o)?,chunk.c.trim())))
+                    .collect::<Result<_,SyncedLyricsError>>()?;
+                Ok(SyncedLine::new(seconds(line.ts)?,line.x).with_end(seconds(line.te)?).with_words(words))
+            })
+            .collect::<Result<_,SyncedLyricsError>>()?;
+        Ok(SyncedLyrics::new(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_times_out_of_range() {
+        assert_eq!(seconds(12.3456), Ok(Duration::from_millis(12_346)));
+        assert_eq!(seconds(-1.0), Err(SyncedLyricsError::InvalidTime("-1".to_owned())));
+        assert_eq!(seconds(1e17), Err(SyncedLyricsError::InvalidTime("100000000000000000".to_owned())));
+        assert!(SyncedLyrics::from_richsync(r#"[{"ts":1e16,"te":1e17,"l":[],"x":"Work it"}]"#).is_err());
+    }
+}
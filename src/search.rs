@@ -0,0 +1,294 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use api_request_utils::serde_json::Value;
+
+use crate::{
+    ArtistId,
+    Language,
+    PartialDate,
+
+    Query,
+    Validated,
+    TrackSearchQuery,
+    ValidationError,
+    SearchSyntaxError,
+    SearchSyntaxErrorKind,
+};
+
+/// The `key:value` keys of the search syntax, in the order they are rendered in, with the parameters they set.
+///
+/// `sort:` and free text, which sets `q`, are handled separately.
+const KEYS : &[(&str,&str)] = &[
+    ("artist", "q_artist"),
+    ("title", "q_track"),
+    ("title_or_artist", "q_track_artist"),
+    ("lyrics", "q_lyrics"),
+    ("writer", "q_writer"),
+    ("artist_id", "f_artist_id"),
+    ("genre", "f_music_genre_id"),
+    ("lang", "f_lyrics_language"),
+    ("has", "f_has_lyrics"),
+    ("after", "f_track_release_group_first_release_date_min"),
+    ("before", "f_track_release_group_first_release_date_max"),
+    ("quorum", "quorum_factor"),
+    ("page", "page"),
+    ("page_size", "page_size"),
+];
+
+/// The values of `sort:` with the parameters and directions they set.
+const SORTS : &[(&str,&str,&str)] = &[
+    ("rating-asc", "s_track_rating", "asc"),
+    ("rating-desc", "s_track_rating", "desc"),
+    ("artist-rating-asc", "s_artist_rating", "asc"),
+    ("artist-rating-desc", "s_artist_rating", "desc"),
+];
+
+/// A single `key:value` pair or free text word of a search string.
+struct Token<'s> {
+    position : usize,
+    text : &'s str,
+    key : Option<&'s str>,
+    value : String,
+}
+
+impl Token<'_> {
+    fn error(&self,kind : SearchSyntaxErrorKind) -> SearchSyntaxError {
+        SearchSyntaxError { position : self.position, token : self.text.to_owned(), kind }
+    }
+}
+
+/// Reads a quoted string starting at byte `start` of `input`, returning its unescaped contents and the byte after the closing quote.
+fn quoted(input : &str,start : usize) -> Option<(String,usize)> {
+    let mut value = String::new();
+    let mut chars = input[start + 1..].char_indices();
+
+    while let Some((index,c)) = chars.next() {
+        match c {
+            '"' => return Some((value,start + 1 + index + 1)),
+            '\\' => value.push(chars.next()?.1),
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// Splits a search string into tokens.
+fn tokenize(input : &str) -> Result<Vec<Token<'_>>,SearchSyntaxError> {
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    loop {
+        position += input[position..].len() - input[position..].trim_start().len();
+        if position == input.len() {
+            return Ok(tokens);
+        }
+
+        let rest = &input[position..];
+        let word_end = |from : usize| from + rest[from..].find(char::is_whitespace).unwrap_or(rest.len() - from);
+        let unterminated = || SearchSyntaxError { position, token : rest.to_owned(), kind : SearchSyntaxErrorKind::UnterminatedQuote };
+
+        let (key,value,end) = match rest.find([':','"']).filter(|at| *at < word_end(0)) {
+            Some(0) if rest.starts_with('"') => {
+                let (value,end) = quoted(rest,0).ok_or_else(unterminated)?;
+                (None,value,end)
+            },
+            Some(at) if rest[at..].starts_with(':') && rest[at + 1..].starts_with('"') => {
+                let (value,end) = quoted(rest,at + 1).ok_or_else(unterminated)?;
+                (Some(&rest[..at]),value,end)
+            },
+            Some(at) if rest[at..].starts_with(':') => {
+                let end = word_end(at);
+                (Some(&rest[..at]),rest[at + 1..end].to_owned(),end)
+            },
+            _ => {
+                let end = word_end(0);
+                (None,rest[..end].to_owned(),end)
+            },
+        };
+
+        tokens.push(Token { position, text : &rest[..end], key, value });
+        position += end;
+    }
+}
+
+/// Parses the value of a `key:value` token into the value of `parameter`.
+///
+/// Returns a description of what was expected when the value is invalid.
+fn parse_value(parameter : &str,value : &str,negated : bool) -> Result<Value,&'static str> {
+    match parameter {
+        "f_has_lyrics" => match value {
+            "lyrics" => Ok(Value::from(!negated)),
+            _ => Err("`lyrics`"),
+        },
+        "f_artist_id" => value.parse::<ArtistId>().map(Value::from).map_err(|_| "a numeric artist id"),
+        "f_music_genre_id" | "page" | "page_size" => value.parse::<u32>().map(Value::from).map_err(|_| "a positive number"),
+        "f_lyrics_language" => value.parse::<Language>().ok()
+            .filter(Language::is_known)
+            .map(|language| Value::from(&language))
+            .ok_or("an ISO 639-1 language code"),
+        "f_track_release_group_first_release_date_min" => value.parse::<PartialDate>()
+            .map(|date| Value::from(PartialDate::format_compact(date.first_day())))
+            .map_err(|_| "a date such as 2001, 2001-06 or 2001-06-30"),
+        "f_track_release_group_first_release_date_max" => value.parse::<PartialDate>()
+            .map(|date| Value::from(PartialDate::format_compact(date.last_day())))
+            .map_err(|_| "a date such as 2001, 2001-06 or 2001-06-30"),
+        "quorum_factor" => value.parse::<f32>().map(|quorum_factor| Value::from(quorum_factor.to_string())).map_err(|_| "a number"),
+        _ => match value.is_empty() {
+            true => Err("a value"),
+            false => Ok(Value::from(value)),
+        },
+    }
+}
+
+/// Quotes `value` if it could not be read back as a single token.
+fn quote(value : &str,bare : bool) -> String {
+    let needs_quotes = value.is_empty()
+        || value.contains(|c : char| c.is_whitespace() || c == '"' || c == '\\')
+        || (bare && value.contains(':'));
+
+    match needs_quotes {
+        false => value.to_owned(),
+        true => format!("\"{}\"",value.replace('\\',"\\\\").replace('"',"\\\"")),
+    }
+}
+
+impl TrackSearchQuery {
+    /// Parses a search string such as `artist:"Daft Punk" title:harder lang:en has:lyrics after:2001-01-01 sort:rating-desc`.
+    ///
+    /// The supported keys are `artist`, `title`, `title_or_artist`, `lyrics`, `writer`, `artist_id`, `genre`,
+    /// `lang`, `has:lyrics` (or `-has:lyrics`), `after`, `before`, `quorum`, `page`, `page_size` and
+    /// `sort` (`rating-asc`, `rating-desc`, `artist-rating-asc` or `artist-rating-desc`). Values containing
+    /// whitespace are quoted, with `\"` and `\\` as escapes. Words without a key are searched for everywhere.
+    ///
+    /// `after` and `before` take partial dates, which cover their whole year or month.
+    pub fn parse(input : &str) -> Result<Validated<TrackSearchQuery>,SearchSyntaxError> {
+        let mut query = TrackSearchQuery::new();
+        let mut words = Vec::new();
+        let mut origins : Vec<(&'static str,Token<'_>)> = Vec::new();
+
+        for token in tokenize(input)? {
+            let Some(key) = token.key else {
+                words.push(token.value);
+                continue;
+            };
+
+            let (key,negated) = match key.strip_prefix('-') {
+                Some("has") => ("has",true),
+                _ => (key,false),
+            };
+
+            let (parameter,value) = match key {
+                "sort" => SORTS.iter()
+                    .find(|(name,_,_)| *name == token.value)
+                    .map(|(_,parameter,direction)| (*parameter,Value::from(*direction)))
+                    .ok_or_else(|| token.error(SearchSyntaxErrorKind::InvalidValue("one of rating-asc, rating-desc, artist-rating-asc or artist-rating-desc")))?,
+                key => {
+                    let parameter = KEYS.iter().find(|(name,_)| *name == key).map(|(_,parameter)| *parameter).ok_or_else(|| token.error(SearchSyntaxErrorKind::UnknownKey))?;
+                    let value = parse_value(parameter,&token.value,negated).map_err(|expected| token.error(SearchSyntaxErrorKind::InvalidValue(expected)))?;
+                    (parameter,value)
+                },
+            };
+
+            if query.0.insert(parameter,value).is_some() {
+                return Err(token.error(SearchSyntaxErrorKind::RepeatedKey));
+            }
+            origins.push((parameter,token));
+        }
+
+        query = query.search_all_contains_word(Some(words.join(" ")).filter(|words| !words.is_empty()).as_deref());
+
+        query.build().map_err(|error| {
+            let origin = match &error {
+                ValidationError::OutOfRange { parameter, .. } => origins.iter().find(|(key,_)| key == parameter),
+                ValidationError::MissingParameter { .. } => None,
+            };
+            match origin {
+                Some((_,token)) => token.error(SearchSyntaxErrorKind::Invalid(error)),
+                None => SearchSyntaxError { position : 0, token : input.to_owned(), kind : SearchSyntaxErrorKind::Invalid(error) },
+            }
+        })
+    }
+}
+
+/// Renders the query in the syntax read by [TrackSearchQuery::parse].
+impl Display for TrackSearchQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut tokens = Vec::new();
+
+        for (key,parameter) in KEYS {
+            let Some(value) = self.0.get(parameter) else { continue };
+
+            tokens.push(match (*parameter,value) {
+                ("f_has_lyrics",Value::Bool(true)) => "has:lyrics".to_owned(),
+                ("f_has_lyrics",_) => "-has:lyrics".to_owned(),
+                ("f_track_release_group_first_release_date_min" | "f_track_release_group_first_release_date_max",Value::String(date)) => match date.parse::<PartialDate>() {
+                    Ok(date) => format!("{key}:{date}"),
+                    Err(_) => format!("{key}:{}",quote(date,false)),
+                },
+                (_,Value::String(value)) => format!("{key}:{}",quote(value,false)),
+                (_,value) => format!("{key}:{value}"),
+            });
+        }
+
+        for (name,parameter,direction) in SORTS {
+            if self.0.get(parameter).and_then(Value::as_str) == Some(*direction) {
+                tokens.push(format!("sort:{name}"));
+            }
+        }
+
+        if let Some(words) = self.0.get("q").and_then(Value::as_str) {
+            tokens.extend(words.split_whitespace().map(|word| quote(word,true)));
+        }
+
+        f.write_str(&tokens.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameter<'q>(query : &'q TrackSearchQuery,key : &str) -> Option<&'q Value> {
+        query.0.get(key)
+    }
+
+    #[test]
+    fn parse_reads_every_key() {
+        let query = TrackSearchQuery::parse(r#"artist:"Daft Punk" title:harder lang:en lyrics:"work it" has:lyrics after:2001 before:2001-06 sort:rating-desc robot rock"#).unwrap();
+
+        assert_eq!(parameter(&query, "q_artist"), Some(&Value::from("Daft Punk")));
+        assert_eq!(parameter(&query, "q_track"), Some(&Value::from("harder")));
+        assert_eq!(parameter(&query, "f_lyrics_language"), Some(&Value::from("en")));
+        assert_eq!(parameter(&query, "q_lyrics"), Some(&Value::from("work it")));
+        assert_eq!(parameter(&query, "f_has_lyrics"), Some(&Value::from(true)));
+        assert_eq!(parameter(&query, "f_track_release_group_first_release_date_min"), Some(&Value::from("20010101")));
+        assert_eq!(parameter(&query, "f_track_release_group_first_release_date_max"), Some(&Value::from("20010630")));
+        assert_eq!(parameter(&query, "s_track_rating"), Some(&Value::from("desc")));
+        assert_eq!(parameter(&query, "q"), Some(&Value::from("robot rock")));
+    }
+
+    #[test]
+    fn render_round_trips() {
+        let input = r#"artist:"Daft Punk" title:"say \"hi\"" artist_id:118 lang:en -has:lyrics after:2001-01-01 quorum:0.5 sort:rating-desc sort:artist-rating-asc "a:b" rock"#;
+        let query = TrackSearchQuery::parse(input).unwrap();
+
+        assert_eq!(query.to_string(), input);
+        assert_eq!(TrackSearchQuery::parse(&query.to_string()).unwrap().0.0, query.0.0);
+        assert_eq!(TrackSearchQuery::new().song_title(Some("one more time")).to_string(), r#"title:"one more time""#);
+    }
+
+    #[test]
+    fn parse_points_at_the_bad_token() {
+        let error = |input : &str| TrackSearchQuery::parse(input).unwrap_err();
+
+        assert_eq!(error("title:x colour:red"), SearchSyntaxError { position : 8, token : "colour:red".to_owned(), kind : SearchSyntaxErrorKind::UnknownKey });
+        assert_eq!(error("lang:xx").kind, SearchSyntaxErrorKind::InvalidValue("an ISO 639-1 language code"));
+        assert_eq!(error("after:2001-13").kind, SearchSyntaxErrorKind::InvalidValue("a date such as 2001, 2001-06 or 2001-06-30"));
+        assert_eq!(error("title:a title:b").kind, SearchSyntaxErrorKind::RepeatedKey);
+        assert_eq!(error(r#"artist:"Daft Punk"#), SearchSyntaxError { position : 0, token : r#"artist:"Daft Punk"#.to_owned(), kind : SearchSyntaxErrorKind::UnterminatedQuote });
+
+        let error = error("robot quorum:2");
+        assert_eq!((error.position, error.token.as_str()), (6, "quorum:2"));
+        assert!(matches!(error.kind, SearchSyntaxErrorKind::Invalid(ValidationError::OutOfRange { parameter : "quorum_factor", .. })));
+    }
+}
@@ -4,12 +4,15 @@
 use api_request_utils::serde::{Deserialize,Serialize};
 use getset::{Getters, MutGetters, Setters};
 
+use crate::{TrackId, CommonTrackId, ArtistId, AlbumId, Mbid, Country, Language, PartialDate, Timestamp, deserialize::{empty_as_none, unknown_as_none}};
+
 #[derive(Getters, Setters, MutGetters)]
 #[derive(Deserialize, Serialize, Debug, PartialEq, PartialOrd,Clone)]
 #[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
 pub struct Translation {
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    language : String,
+    #[serde(default, deserialize_with="empty_as_none")]
+    language : Option<Language>,
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     translation : String
 }
@@ -19,7 +22,7 @@ pub struct Translation {
 pub struct Artist {
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename="artist_id")]
-    id : u16,
+    id : ArtistId,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename="artist_name")]
@@ -34,8 +37,8 @@ pub struct Artist {
     comment : String,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename="artist_country")]
-    country : String,
+    #[serde(rename="artist_country", default, deserialize_with="unknown_as_none")]
+    country : Option<Country>,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename="artist_alias_list")]
@@ -54,16 +57,16 @@ pub struct Artist {
     begin_year : u16,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename="begin_date")]
-    begin_data : String,
+    #[serde(rename="begin_date", default, deserialize_with="unknown_as_none")]
+    begin_data : Option<PartialDate>,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename="end_date_year")]
     end_year : u16,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename="end_date")]
-    end_data : String,
+    #[serde(rename="end_date", default, deserialize_with="unknown_as_none")]
+    end_data : Option<PartialDate>,
 }
 
 #[derive(Getters, Setters, MutGetters)]
@@ -72,7 +75,7 @@ pub struct Artist {
 pub struct Track {
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "track_id")]
-    id: u32,
+    id: TrackId,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "track_name")]
@@ -88,7 +91,7 @@ pub struct Track {
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "commontrack_id")]
-    common_track_id: u32,
+    common_track_id: CommonTrackId,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "instrumental")]
@@ -112,7 +115,7 @@ pub struct Track {
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "album_id")]
-    album_id: u32,
+    album_id: AlbumId,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "album_name")]
@@ -120,7 +123,7 @@ pub struct Track {
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "artist_id")]
-    artist_id: u32,
+    artist_id: ArtistId,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "artist_name")]
@@ -139,8 +142,8 @@ pub struct Track {
     is_restricted: bool,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename = "updated_time")]
-    updated_time: String,
+    #[serde(rename = "updated_time", default, deserialize_with="unknown_as_none")]
+    updated_time: Option<Timestamp>,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "primary_genres")]
@@ -201,8 +204,8 @@ pub struct Lyrics {
     lyrics: String,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename = "lyrics_language")]
-    language: String,
+    #[serde(rename = "lyrics_language", default, deserialize_with="empty_as_none")]
+    language: Option<Language>,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "script_tracking_url")]
@@ -221,8 +224,8 @@ pub struct Lyrics {
     backlink_url: String,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename = "updated_time")]
-    updated_time: String,
+    #[serde(rename = "updated_time", default, deserialize_with="unknown_as_none")]
+    updated_time: Option<Timestamp>,
 }
 
 #[derive(Getters, Setters, MutGetters)]
@@ -260,8 +263,8 @@ pub struct RawData {
 #[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
 pub struct Snippet {
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename = "snippet_language")]
-    language: String,
+    #[serde(rename = "snippet_language", default, deserialize_with="empty_as_none")]
+    language: Option<Language>,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "snippet_id")]
@@ -292,8 +295,8 @@ pub struct Snippet {
     html_tracking_url: String,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename = "updated_time")]
-    updated_time: String,
+    #[serde(rename = "updated_time", default, deserialize_with="unknown_as_none")]
+    updated_time: Option<Timestamp>,
 }
 
 #[derive(Getters, Setters, MutGetters)]
@@ -312,7 +315,8 @@ pub struct Subtitle {
     subtitle_body: String,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    subtitle_language: String,
+    #[serde(default, deserialize_with="empty_as_none")]
+    subtitle_language: Option<Language>,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     script_tracking_url: String,
@@ -327,17 +331,31 @@ pub struct Subtitle {
     lyrics_copyright: String,
 }
 
+/// A subtitle returned by the matcher together with the track matched on the same title, artist and album.
+///
+/// The track is matched separately and is `None` if that failed, see [MusixAbgleich::subtitle](crate::MusixAbgleich::subtitle).
+#[derive(Getters, Setters, MutGetters)]
+#[derive(Deserialize, Serialize, Debug, PartialEq,PartialOrd ,Clone)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+pub struct MatchedSubtitle {
+    #[getset(get = "pub",set = "pub", get_mut = "pub")]
+    pub(crate) subtitle: Subtitle,
+
+    #[getset(get = "pub",set = "pub", get_mut = "pub")]
+    pub(crate) track: Option<Track>,
+}
+
 #[derive(Getters, Setters, MutGetters)]
 #[derive(Deserialize, Serialize, Debug, PartialEq,PartialOrd ,Clone)]
 #[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
 pub struct Album {
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "album_id")]
-    id: u32,
+    id: AlbumId,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename = "album_mbid")]
-    music_brainz_identifier: Option<String>,
+    #[serde(rename = "album_mbid", default, deserialize_with = "unknown_as_none")]
+    music_brainz_identifier: Option<Mbid>,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "album_name")]
@@ -348,12 +366,12 @@ pub struct Album {
     rating: u32,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename = "album_release_date")]
-    release_date: String,
+    #[serde(rename = "album_release_date", default, deserialize_with="unknown_as_none")]
+    release_date: Option<PartialDate>,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "artist_id")]
-    artist_id: u32,
+    artist_id: ArtistId,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
     #[serde(rename = "artist_name")]
@@ -384,8 +402,8 @@ pub struct Album {
     external_identities: ExternalIdentities,
 
     #[getset(get = "pub",set = "pub", get_mut = "pub")]
-    #[serde(rename = "updated_time")]
-    updated_time: String,
+    #[serde(rename = "updated_time", default, deserialize_with="unknown_as_none")]
+    updated_time: Option<Timestamp>,
 }
 
 #[derive(Getters, Setters, MutGetters)]
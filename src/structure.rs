@@ -0,0 +1,265 @@
+use std::collections::{HashMap, HashSet};
+
+use getset::Getters;
+use strum::Display;
+
+use crate::{CleanLyrics, Lyrics};
+
+/// The part of a song a stanza belongs to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Display)]
+#[allow(missing_docs)]
+pub enum SectionKind {
+    #[strum(serialize = "Intro")]
+    Intro,
+    #[strum(serialize = "Verse")]
+    Verse,
+    #[strum(serialize = "Pre-Chorus")]
+    PreChorus,
+    #[strum(serialize = "Chorus")]
+    Chorus,
+    #[strum(serialize = "Hook")]
+    Hook,
+    #[strum(serialize = "Bridge")]
+    Bridge,
+    #[strum(serialize = "Outro")]
+    Outro,
+    /// A section marked with a name that is not recognised, e.g. `[Instrumental]`.
+    #[strum(serialize = "Unknown")]
+    Unknown,
+}
+
+impl SectionKind {
+    /// Recognises the name of a section marker such as `Verse 2: Artist` or `Chorus x2`.
+    fn from_marker(marker : &str) -> Option<Self> {
+        let name = marker.split(':').next().unwrap_or_default().to_lowercase().replace(['-',' '],"");
+        let kinds = [("prechorus",SectionKind::PreChorus),("chorus",SectionKind::Chorus),("refrain",SectionKind::Chorus),("hook",SectionKind::Hook),
+            ("verse",SectionKind::Verse),("bridge",SectionKind::Bridge),("intro",SectionKind::Intro),("outro",SectionKind::Outro)];
+        kinds.into_iter().find(|(prefix,_)| name.starts_with(prefix)).map(|(_,kind)| kind)
+    }
+}
+
+/// A group of lines separated from the others by blank lines or a section marker.
+#[derive(Getters)]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Stanza {
+    /// The lines, without the section marker.
+    #[getset(get = "pub")]
+    lines : Vec<String>,
+
+    /// The section marker the stanza started with, e.g. `Verse 2: Artist` for `[Verse 2: Artist]`.
+    #[getset(get = "pub")]
+    label : Option<String>,
+
+    /// The part of the song the stanza was found to be.
+    #[getset(get = "pub")]
+    kind : SectionKind,
+
+    /// How sure the analysis is of [Stanza::kind], from 0 to 1. Explicit markers have a confidence of 1.
+    #[getset(get = "pub")]
+    confidence : f32,
+
+    /// The index of the first stanza this one repeats, if any.
+    #[getset(get = "pub")]
+    repeat_of : Option<usize>,
+}
+
+/// A line sung more than once.
+#[derive(Getters)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RepeatedLine {
+    /// The line as first written.
+    #[getset(get = "pub")]
+    text : String,
+
+    /// How many times the line is sung.
+    #[getset(get = "pub")]
+    count : usize,
+
+    /// The indexes of the stanzas the line is sung in.
+    #[getset(get = "pub")]
+    stanzas : Vec<usize>,
+}
+
+/// The stanzas of a song with their parts labelled, and the lines that repeat, see [Lyrics::structure].
+#[derive(Getters)]
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct LyricsStructure {
+    /// The stanzas in the order they are sung.
+    #[getset(get = "pub")]
+    stanzas : Vec<Stanza>,
+
+    /// The lines sung more than once, the most repeated first.
+    #[getset(get = "pub")]
+    repeated_lines : Vec<RepeatedLine>,
+}
+
+/// A stanza being read: its marker, if any, and its lines.
+type Draft = (Option<(String,SectionKind)>,Vec<String>);
+
+/// Reads a section marker: `[Anything]`, or a recognised name in parentheses or followed by a colon such as `(Chorus)` or `Chorus:`.
+fn marker(line : &str) -> Option<(String,SectionKind)> {
+    if let Some(inner) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+        return Some((inner.trim().to_owned(),SectionKind::from_marker(inner.trim()).unwrap_or(SectionKind::Unknown)));
+    }
+    let inner = line.strip_prefix('(').and_then(|line| line.strip_suffix(')'))
+        .or_else(|| line.strip_suffix(':').filter(|inner| inner.split_whitespace().count() <= 3))?
+        .trim();
+    SectionKind::from_marker(inner).map(|kind| (inner.to_owned(),kind))
+}
+
+/// The line lowercased, with only letters, digits and single spaces, so that small differences don't hide a repeat.
+fn normalise(line : &str) -> String {
+    line.to_lowercase().split(|c : char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).collect::<Vec<_>>().join(" ")
+}
+
+/// The share of lines two stanzas have in common.
+fn similarity(a : &HashSet<String>,b : &HashSet<String>) -> f32 {
+    match a.len().max(b.len()) {
+        0 => 0.0,
+        len => a.intersection(b).count() as f32 / len as f32,
+    }
+}
+
+impl LyricsStructure {
+    /// Splits lyrics into stanzas, finds repeated stanzas and lines and labels every stanza.
+    ///
+    /// Stanzas with an explicit marker such as `[Chorus]` take its kind, or [SectionKind::Unknown] if it is not
+    /// recognised. Otherwise the stanza repeated most often is the chorus, stanzas repeated right before every chorus
+    /// are pre-choruses, a late stanza sung once between choruses is the bridge and other stanzas are verses, with
+    /// short ones at the very start or end being the intro or outro. A marker on its own, e.g. `[Chorus]`, repeats the
+    /// last stanza of that kind. The lyrics are cleaned with [CleanLyrics::new] first.
+    pub fn analyze(lyrics : &str) -> Self {
+        let mut drafts : Vec<Draft> = vec![(None,Vec::new())];
+        for line in CleanLyrics::new(lyrics).lines() {
+            let current = drafts.last_mut().expect("there is always a stanza being read");
+            match (line.is_empty(),marker(line)) {
+                (true,_) => drafts.push((None,Vec::new())),
+                (false,Some(label)) if current.1.is_empty() && current.0.is_none() => current.0 = Some(label),
+                (false,Some(label)) => drafts.push((Some(label),Vec::new())),
+                (false,None) => current.1.push(line.clone()),
+            }
+        }
+        drafts.retain(|(label,lines)| label.is_some() || !lines.is_empty());
+
+        let keys : Vec<HashSet<String>> = drafts.iter().map(|(_,lines)| lines.iter().map(|line| normalise(line)).filter(|line| !line.is_empty()).collect()).collect();
+        let explicit = |index : usize| drafts[index].0.as_ref().map(|(_,kind)| *kind).filter(|kind| *kind != SectionKind::Unknown);
+
+        let mut roots : Vec<usize> = Vec::with_capacity(drafts.len());
+        for index in 0..drafts.len() {
+            let root = match keys[index].is_empty() {
+                true => explicit(index).and_then(|kind| (0..index).rev().find(|other| explicit(*other) == Some(kind))),
+                false => (0..index).find(|other| similarity(&keys[index],&keys[*other]) >= 0.6),
+            };
+            roots.push(root.map_or(index,|root| roots[root]));
+        }
+        let occurrences = |root : usize| roots.iter().filter(|other| **other == root).count();
+
+        let chorus = (0..drafts.len()).find(|index| explicit(*index) == Some(SectionKind::Chorus)).map(|index| roots[index])
+            .or_else(|| roots.iter().copied().filter(|root| occurrences(*root) > 1).max_by_key(|root| (occurrences(*root),keys[*root].len(),usize::MAX - root)));
+        let is_chorus = |index : usize| chorus == Some(roots[index]);
+        let choruses_before = |index : usize| (0..index).filter(|other| is_chorus(*other)).count();
+        let last = drafts.len().saturating_sub(1);
+
+        let stanzas = drafts.iter().enumerate()
+            .map(|(index,(label,lines))| {
+                let count = occurrences(roots[index]);
+                let (kind,confidence) = match label {
+                    Some((_,kind)) => (*kind,1.0),
+                    None if is_chorus(index) => (SectionKind::Chorus,(0.5 + 0.15 * count as f32).min(0.95)),
+                    None if count > 1 => match (0..drafts.len()).filter(|other| roots[*other] == roots[index]).all(|other| other < last && is_chorus(other + 1)) {
+                        true => (SectionKind::PreChorus,0.6),
+                        false => (SectionKind::Chorus,(0.3 + 0.1 * count as f32).min(0.7)),
+                    },
+                    None if last > 0 && index == 0 && lines.len() <= 2 => (SectionKind::Intro,0.4),
+                    None if last > 0 && index == last && lines.len() <= 2 => (SectionKind::Outro,0.4),
+                    None if choruses_before(index) >= 2 && index < last && is_chorus(index + 1)
+                        && (index + 1..=last).all(|other| occurrences(roots[other]) > 1 || other == last) => (SectionKind::Bridge,0.5),
+                    None => (SectionKind::Verse,if chorus.is_some() { 0.7 } else { 0.5 }),
+                };
+
+                Stanza {
+                    lines : lines.clone(),
+                    label : label.as_ref().map(|(label,_)| label.clone()),
+                    kind,
+                    confidence,
+                    repeat_of : Some(roots[index]).filter(|root| *root != index),
+                }
+            })
+            .collect();
+
+        let mut repeated : Vec<RepeatedLine> = Vec::new();
+        let mut found : HashMap<String,usize> = HashMap::new();
+        for (index,(_,lines)) in drafts.iter().enumerate() {
+            for line in lines {
+                let key = normalise(line);
+                if key.is_empty() {
+                    continue;
+                }
+                let position = *found.entry(key).or_insert_with(|| {
+                    repeated.push(RepeatedLine { text : line.clone(), count : 0, stanzas : Vec::new() });
+                    repeated.len() - 1
+                });
+                repeated[position].count += 1;
+                if repeated[position].stanzas.last() != Some(&index) {
+                    repeated[position].stanzas.push(index);
+                }
+            }
+        }
+        repeated.retain(|line| line.count > 1);
+        repeated.sort_by_key(|line| std::cmp::Reverse(line.count));
+
+        LyricsStructure { stanzas, repeated_lines : repeated }
+    }
+
+    /// The first stanza labelled as the chorus.
+    pub fn chorus(&self) -> Option<&Stanza> {
+        self.stanzas.iter().find(|stanza| stanza.kind == SectionKind::Chorus)
+    }
+
+    /// The most repeated line, if it is sung at least three times.
+    pub fn hook(&self) -> Option<&RepeatedLine> {
+        self.repeated_lines.first().filter(|line| line.count >= 3)
+    }
+}
+
+impl Lyrics {
+    /// Analyses the stanzas of the lyrics, see [LyricsStructure::analyze].
+    pub fn structure(&self) -> LyricsStructure {
+        LyricsStructure::analyze(self.lyrics())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(structure : &LyricsStructure) -> Vec<SectionKind> {
+        structure.stanzas().iter().map(|stanza| *stanza.kind()).collect()
+    }
+
+    #[test]
+    fn finds_the_chorus_without_markers() {
+        let lyrics = "Oh yeah\n\nFirst verse line\nAnother line\n\nRound and round\nWe go round\n\nSecond verse line\nOne more line\n\nRound and round\nWe go round!\n\nThe bridge is here\nOnly sung once\nand different\n\nRound and round\nWe go round\n\n...";
+        let structure = LyricsStructure::analyze(lyrics);
+
+        use SectionKind::*;
+        assert_eq!(kinds(&structure), [Intro, Verse, Chorus, Verse, Chorus, Bridge, Chorus]);
+        assert_eq!(*structure.stanzas()[4].repeat_of(), Some(2));
+        assert_eq!(structure.chorus().map(Stanza::lines), Some(&vec!["Round and round".to_owned(), "We go round".to_owned()]));
+        assert_eq!(structure.hook().map(|hook| (hook.text().as_str(), *hook.count(), hook.stanzas().clone())), Some(("Round and round", 3, vec![2, 4, 6])));
+    }
+
+    #[test]
+    fn uses_explicit_markers() {
+        let lyrics = "[Verse 1: Artist]\nFirst line\nSecond line\n[Chorus]\nSing it\nSing it loud\n\n(Verse 2)\nThird line\n\n[Chorus]\n\n[Instrumental]";
+        let structure = LyricsStructure::analyze(lyrics);
+
+        use SectionKind::*;
+        assert_eq!(kinds(&structure), [Verse, Chorus, Verse, Chorus, Unknown]);
+        assert_eq!(structure.stanzas()[0].label().as_deref(), Some("Verse 1: Artist"));
+        assert_eq!(*structure.stanzas()[0].confidence(), 1.0);
+        assert_eq!(*structure.stanzas()[3].repeat_of(), Some(1));
+        assert!(structure.stanzas()[3].lines().is_empty());
+        assert_eq!(structure.hook(), None);
+    }
+}
@@ -0,0 +1,190 @@
+use std::time::Duration;
+
+use api_request_utils::serde::{Deserialize,Serialize};
+use getset::{Getters, Setters, MutGetters};
+
+use crate::{Subtitle, SyncedLyricsError, Track};
+use crate::mxm::is_mxm;
+
+/// A word of a line, with the time it starts being sung at. Only richsync data times single words.
+#[derive(Getters, Setters, MutGetters)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Clone)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+pub struct SyncedWord {
+    /// The time from the start of the song.
+    #[getset(get = "pub",set = "pub", get_mut = "pub")]
+    time : Duration,
+
+    /// The word, without surrounding whitespace.
+    #[getset(get = "pub",set = "pub", get_mut = "pub")]
+    text : String,
+}
+
+impl SyncedWord {
+    /// Creates a word starting at `time`.
+    pub fn new(time : Duration,text : impl Into<String>) -> Self {
+        SyncedWord { time, text : text.into() }
+    }
+}
+
+/// A line of lyrics together with the time it starts being sung at.
+#[derive(Getters, Setters, MutGetters)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Clone)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+pub struct SyncedLine {
+    /// The time from the start of the song.
+    #[getset(get = "pub",set = "pub", get_mut = "pub")]
+    time : Duration,
+
+    /// The time the line stops being shown at, if the format carries it. LRC only records start times.
+    #[getset(get = "pub",set = "pub", get_mut = "pub")]
+    #[serde(default)]
+    end : Option<Duration>,
+
+    /// The text of the line, empty for instrumental breaks. Lines shown on several rows are separated by `\n`.
+    #[getset(get = "pub",set = "pub", get_mut = "pub")]
+    text : String,
+
+    /// The words of the line with their own times, empty unless the source is word-timed.
+    #[getset(get = "pub",set = "pub", get_mut = "pub")]
+    #[serde(default)]
+    words : Vec<SyncedWord>,
+}
+
+impl SyncedLine {
+    /// Creates a line starting at `time`.
+    pub fn new(time : Duration,text : impl Into<String>) -> Self {
+        SyncedLine { time, end : None, text : text.into(), words : Vec::new() }
+    }
+
+    /// Sets the timed words of the line.
+    pub fn with_words(mut self,words : Vec<SyncedWord>) -> Self {
+        self.words = words;
+        self
+    }
+
+    /// Sets the time the line stops being shown at.
+    pub fn with_end(mut self,end : Duration) -> Self {
+        self.end = Some(end);
+        self
+    }
+}
+
+/// Time-synchronised lyrics: metadata tags plus lines ordered by the time they start at.
+#[derive(Getters, Setters, MutGetters)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Hash, Clone, Default)]
+#[serde(crate = "api_request_utils::serde")] // must be below the derive attribute
+pub struct SyncedLyrics {
+    /// The metadata tags such as `ar` (artist) or `ti` (title), in the order they were read in.
+    #[getset(get = "pub")]
+    pub(crate) tags : Vec<(String,String)>,
+
+    /// The lines, ordered by time.
+    #[getset(get = "pub")]
+    pub(crate) lines : Vec<SyncedLine>,
+}
+
+impl SyncedLyrics {
+    /// Creates lyrics from the given lines, sorting them by time.
+    pub fn new(lines : Vec<SyncedLine>) -> Self {
+        let mut lyrics = SyncedLyrics { tags : Vec::new(), lines };
+        lyrics.lines.sort_by_key(|line| line.time);
+        lyrics
+    }
+
+    /// Consumes the lyrics, returning the lines.
+    pub fn into_lines(self) -> Vec<SyncedLine> {
+        self.lines
+    }
+
+    /// Adds a line, keeping the lines ordered by time. Lines with the same time keep their insertion order.
+    pub fn push_line(&mut self,line : SyncedLine) {
+        let index = self.lines.partition_point(|other| other.time <= line.time);
+        self.lines.insert(index,line);
+    }
+
+    /// The value of the metadata tag `name`, e.g. `ar`.
+    pub fn tag(&self,name : &str) -> Option<&str> {
+        self.tags.iter().find(|(key,_)| key == name).map(|(_,value)| value.as_str())
+    }
+
+    /// Sets the metadata tag `name`, replacing its value if present and appending it otherwise.
+    pub fn set_tag(&mut self,name : impl Into<String>,value : impl Into<String>) {
+        let (name,value) = (name.into(),value.into());
+        match self.tags.iter_mut().find(|(key,_)| *key == name) {
+            Some(tag) => tag.1 = value,
+            None => self.tags.push((name,value)),
+        }
+    }
+
+    /// Removes the metadata tag `name`, returning its value.
+    pub fn remove_tag(&mut self,name : &str) -> Option<String> {
+        let index = self.tags.iter().position(|(key,_)| key == name)?;
+        Some(self.tags.remove(index).1)
+    }
+
+    /// The artist (`ar` tag).
+    pub fn artist(&self) -> Option<&str> {
+        self.tag("ar")
+    }
+
+    /// The song title (`ti` tag).
+    pub fn title(&self) -> Option<&str> {
+        self.tag("ti")
+    }
+
+    /// The album (`al` tag).
+    pub fn album(&self) -> Option<&str> {
+        self.tag("al")
+    }
+
+    /// The author of the lyrics (`au` tag).
+    pub fn author(&self) -> Option<&str> {
+        self.tag("au")
+    }
+
+    /// The length of the song (`length` tag, `mm:ss`).
+    pub fn length(&self) -> Option<Duration> {
+        let (minutes,seconds) = self.tag("length")?.trim().split_once(':')?;
+        let seconds : f64 = seconds.parse().ok().filter(|seconds : &f64| (0.0..60.0).contains(seconds))?;
+        Duration::from_secs(minutes.parse::<u64>().ok()?.checked_mul(60)?).checked_add(Duration::from_secs_f64(seconds))
+    }
+
+    /// The offset in milliseconds (`offset` tag) by which players shift every line, a positive offset making lines appear sooner.
+    ///
+    /// The offset is not applied to the times of the lines.
+    pub fn offset(&self) -> Option<i64> {
+        self.tag("offset")?.trim().parse().ok()
+    }
+
+    /// Fills the `ar`, `ti` and `al` tags from the artist, name and album of `track`, leaving the others alone.
+    pub fn set_track_tags(&mut self,track : &Track) {
+        for (name,value) in [("ar",track.artist_name()),("ti",track.name()),("al",track.album_name())] {
+            if !value.is_empty() {
+                self.set_tag(name,value.as_str());
+            }
+        }
+    }
+
+    /// Whether there are no lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
+
+impl Subtitle {
+    /// Parses the subtitle body into synchronised lyrics, whichever of [SubtitleFormat::Lrc](crate::SubtitleFormat::Lrc),
+    /// [SubtitleFormat::Dfxp](crate::SubtitleFormat::Dfxp) or [SubtitleFormat::Mxm](crate::SubtitleFormat::Mxm) it was requested in.
+    ///
+    /// Malformed LRC lines are skipped, see [SyncedLyrics::from_lrc]. DFXP bodies must be well-formed XML.
+    pub fn synced_lyrics(&self) -> Result<SyncedLyrics,SyncedLyricsError> {
+        let body = self.subtitle_body();
+        if is_mxm(body) {
+            return SyncedLyrics::from_mxm(body);
+        }
+        match body.trim_start().starts_with('<') {
+            true => SyncedLyrics::from_dfxp(body),
+            false => Ok(SyncedLyrics::from_lrc(body)),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Helpers shared by the unit tests.
+
+use std::fmt::Debug;
+
+use api_request_utils::reqwest::Client;
+
+use crate::{Isrc, Mbid, Query, ValidationError};
+
+/// Renders the query string exactly as the client would send it, minus the api key.
+pub(crate) fn query_string<Q : Query + Debug>(query : Q) -> String {
+    let query = query.build().unwrap();
+    let mut parameters : Vec<_> = query.parameters().iter().collect();
+    parameters.sort_by_key(|(key, _)| **key);
+
+    let request = Client::new().get(format!("https://api.musixmatch.com/ws/1.1/{}", Q::ENDPOINT)).query(&parameters).build().unwrap();
+    request.url().query().unwrap_or_default().to_owned()
+}
+
+/// The parameters `query` fails to build for lacking.
+pub(crate) fn missing<Q : Query + Debug>(query : Q) -> &'static [&'static str] {
+    match query.build() {
+        Err(ValidationError::MissingParameter { parameters }) => parameters,
+        other => panic!("expected a missing parameter error, got {other:?}"),
+    }
+}
+
+/// The parameter `query` fails to build for being out of range.
+pub(crate) fn out_of_range<Q : Query + Debug>(query : Q) -> &'static str {
+    match query.build() {
+        Err(ValidationError::OutOfRange { parameter, .. }) => parameter,
+        other => panic!("expected an out of range error, got {other:?}"),
+    }
+}
+
+pub(crate) fn isrc() -> Isrc {
+    "USVI20400001".parse().unwrap()
+}
+
+pub(crate) fn mbid() -> Mbid {
+    "056e4f3e-d505-4dad-8ec1-d04f521cbb56".parse().unwrap()
+}
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use crate::SyncedLyrics;
+
+/// Adds `millis` to `time`, stopping at zero.
+fn add_millis(time : Duration,millis : i64) -> Duration {
+    match millis.is_negative() {
+        true => time.saturating_sub(Duration::from_millis(millis.unsigned_abs())),
+        false => time + Duration::from_millis(millis as u64),
+    }
+}
+
+impl SyncedLyrics {
+    /// Applies `map` to the start and end of every line and word.
+    fn map_times(&mut self,map : impl Fn(Duration) -> Duration) {
+        for line in &mut self.lines {
+            let _ = line.set_time(map(*line.time()));
+            if let Some(end) = *line.end() {
+                let _ = line.set_end(Some(map(end)));
+            }
+            for word in line.words_mut() {
+                let _ = word.set_time(map(*word.time()));
+            }
+        }
+    }
+
+    /// Moves every line `millis` milliseconds later, or sooner if negative. Lines moved before the start of the song start at zero.
+    pub fn shift(&mut self,millis : i64) {
+        self.map_times(|time| add_millis(time,millis));
+    }
+
+    /// Applies the `offset` tag to the times of the lines and removes it, so players that ignore the tag show the lines on time.
+    pub fn apply_offset(&mut self) {
+        if let Some(offset) = self.offset() {
+            self.shift(-offset);
+        }
+        let _ = self.remove_tag("offset");
+    }
+
+    /// Stretches the timings linearly, so that lyrics timed against a song of `from` length fit one of `to` length.
+    ///
+    /// The `length` tag, if present, is set to `to`.
+    pub fn stretch(&mut self,from : Duration,to : Duration) {
+        self.resync((Duration::ZERO,Duration::ZERO),(from,to));
+        if self.tag("length").is_some() {
+            self.set_tag("length",format!("{:02}:{:02}",to.as_secs() / 60,to.as_secs() % 60));
+        }
+    }
+
+    /// Maps the timings linearly so that both anchors line up: a line at the first time of an anchor moves to its second time.
+    ///
+    /// Use two points that are easy to recognise in both recordings, e.g. the first and the last line. If the
+    /// anchors share their first time, the lines are only shifted by the first anchor.
+    pub fn resync(&mut self,first : (Duration,Duration),second : (Duration,Duration)) {
+        let millis = |time : Duration| time.as_secs_f64() * 1000.0;
+        let (from,to) = (millis(first.0),millis(first.1));
+        let span = millis(second.0) - from;
+        let factor = if span == 0.0 { 1.0 } else { (millis(second.1) - to) / span };
+        self.map_times(|time| Duration::from_millis((to + (millis(time) - from) * factor).round().max(0.0) as u64));
+        self.lines.sort_by_key(|line| *line.time());
+    }
+
+    /// Fits the lines to audio of the given `duration`: lines starting at or after it are removed, and end times
+    /// and words past it are cut.
+    pub fn trim(&mut self,duration : Duration) {
+        self.lines.retain(|line| *line.time() < duration);
+        for line in &mut self.lines {
+            if let Some(end) = *line.end() {
+                let _ = line.set_end(Some(end.min(duration)));
+            }
+            line.words_mut().retain(|word| *word.time() < duration);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SyncedLine;
+
+    fn times(lyrics : &SyncedLyrics) -> Vec<u128> {
+        lyrics.lines().iter().map(|line| line.time().as_millis()).collect()
+    }
+
+    #[test]
+    fn shifts_stretches_resyncs_and_trims() {
+        let mut lyrics = SyncedLyrics::from_lrc("[length:03:32]\n[offset:500]\n[00:00.20]a\n[00:10.00]b\n[03:30.00]c");
+
+        lyrics.apply_offset();
+        assert_eq!(times(&lyrics), [0, 9_500, 209_500]);
+        assert_eq!(lyrics.offset(), None);
+
+        lyrics.shift(500);
+        assert_eq!(times(&lyrics), [500, 10_000, 210_000]);
+
+        lyrics.stretch(Duration::from_secs(212), Duration::from_secs(215));
+        assert_eq!(times(&lyrics), [507, 10_142, 212_972]);
+        assert_eq!(lyrics.length(), Some(Duration::from_secs(215)));
+
+        lyrics.resync((Duration::from_millis(10_142), Duration::from_secs(10)), (Duration::from_millis(212_972), Duration::from_secs(212)));
+        assert_eq!(times(&lyrics), [404, 10_000, 212_000]);
+
+        lyrics.push_line(SyncedLine::new(Duration::from_secs(200), "d").with_end(Duration::from_secs(220)));
+        lyrics.trim(Duration::from_secs(210));
+        assert_eq!(times(&lyrics), [404, 10_000, 200_000]);
+        assert_eq!(*lyrics.lines()[2].end(), Some(Duration::from_secs(210)));
+    }
+}
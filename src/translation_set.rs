@@ -0,0 +1,98 @@
+use getset::Getters;
+
+use crate::{AlignedLyrics, Language, Lyrics};
+
+/// The lyrics of a track translated into one language.
+#[derive(Getters)]
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct TranslatedLyrics {
+    /// The language the lyrics were translated into.
+    #[getset(get = "pub")]
+    language : Language,
+
+    /// The translated lyrics.
+    #[getset(get = "pub")]
+    lyrics : Lyrics,
+
+    /// The share of original lines that are translated, from 0 to 1, see [AlignedLyrics::completeness].
+    #[getset(get = "pub")]
+    completeness : f32,
+}
+
+/// The translations of a track's lyrics into several languages, see [MusixAbgleich::track_lyrics_translation_set](crate::MusixAbgleich::track_lyrics_translation_set).
+#[derive(Getters)]
+#[derive(Debug, PartialEq, PartialOrd, Clone)]
+pub struct TranslationSet {
+    /// The lyrics in their original language.
+    #[getset(get = "pub")]
+    original : Lyrics,
+
+    /// The translations, in the order their languages were asked for.
+    #[getset(get = "pub")]
+    translations : Vec<TranslatedLyrics>,
+}
+
+impl TranslationSet {
+    /// Keeps the translations that exist, measuring their completeness against `original`.
+    /// The first translation of every language is kept.
+    pub fn new(original : Lyrics,translations : impl IntoIterator<Item = (Language,Option<Lyrics>)>) -> Self {
+        let mut kept : Vec<TranslatedLyrics> = Vec::new();
+        for (language,lyrics) in translations {
+            let Some(lyrics) = lyrics else { continue };
+            if kept.iter().any(|translation| translation.language == language) {
+                continue;
+            }
+            let completeness = AlignedLyrics::new(original.lyrics(),lyrics.lyrics()).completeness();
+            kept.push(TranslatedLyrics { language, lyrics, completeness });
+        }
+        TranslationSet { original, translations : kept }
+    }
+
+    /// The translation into `language`, if it was fetched.
+    pub fn get(&self,language : &Language) -> Option<&TranslatedLyrics> {
+        self.translations.iter().find(|translation| translation.language == *language)
+    }
+
+    /// The languages there are translations into.
+    pub fn languages(&self) -> impl Iterator<Item = &Language> {
+        self.translations.iter().map(|translation| &translation.language)
+    }
+
+    /// The number of translations.
+    pub fn len(&self) -> usize {
+        self.translations.len()
+    }
+
+    /// Whether there are no translations.
+    pub fn is_empty(&self) -> bool {
+        self.translations.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use api_request_utils::serde_json::{self, json};
+
+    fn lyrics(body : &str) -> Lyrics {
+        serde_json::from_value(json!({
+            "lyrics_id" : 1, "restricted" : false, "instrumental" : false, "explicit" : false, "lyrics_body" : body, "lyrics_language" : "en",
+            "script_tracking_url" : "", "pixel_tracking_url" : "", "lyrics_copyright" : "", "backlink_url" : "", "updated_time" : "2020-01-01T00:00:00Z",
+        })).unwrap()
+    }
+
+    #[test]
+    fn keeps_existing_translations() {
+        let set = TranslationSet::new(lyrics("Work it\nMake it\nDo it\nMakes us"), [
+            (Language::French, Some(lyrics("Travaille\nFais-le\nFais-le\nNous rend"))),
+            (Language::German, None),
+            (Language::Spanish, Some(lyrics("Trabájalo\nHazlo"))),
+            (Language::French, Some(lyrics("Autre"))),
+        ]);
+
+        assert_eq!(set.languages().collect::<Vec<_>>(), [&Language::French, &Language::Spanish]);
+        assert_eq!(set.get(&Language::French).map(TranslatedLyrics::completeness), Some(&1.0));
+        assert_eq!(set.get(&Language::Spanish).map(TranslatedLyrics::completeness), Some(&0.5));
+        assert_eq!(set.get(&Language::German), None);
+    }
+}